@@ -1,8 +1,9 @@
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Bound {
     Open,
-    Closed
+    Closed,
 }
+
 impl Bound {
     pub fn is_open(&self) -> bool {
         *self == Bound::Open
@@ -15,8 +16,8 @@ impl Bound {
 
 #[cfg(test)]
 mod test {
-    use rstest::rstest;
     use super::Bound;
+    use rstest::rstest;
 
     #[rstest]
     #[case(Bound::Open, true)]
@@ -31,4 +32,4 @@ mod test {
     fn test_is_closed(#[case] input: Bound, #[case] expected: bool) {
         assert_eq!(input.is_closed(), expected);
     }
-}
\ No newline at end of file
+}
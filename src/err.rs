@@ -1,22 +1,356 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::io;
 
-#[derive(Debug, PartialEq)]
+/// The category of a [`SvartError`], stable across the changes of the error messages.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    IllegalValue,
+    Parse,
+    OutOfBounds,
+    Io,
+    UnknownContig,
+    AmbiguousContig,
+    InvalidRegion,
+    InvalidContigLength,
+    InvalidContigName,
+    Other,
+}
+
+#[derive(Debug)]
 pub enum SvartError {
     IllegalValueError(&'static str),
+    /// The input, e.g. a region or a line of an assembly report, could not be parsed.
+    ParseError {
+        /// The name of the input, e.g. the file name, if known.
+        source_name: Option<String>,
+        /// The one-based line of the input, if the input is read line by line.
+        line: Option<usize>,
+        /// The one-based column where the offending `token` starts.
+        column: Option<usize>,
+        /// The offending part of the input, if any.
+        token: Option<String>,
+        message: String,
+    },
+    /// An I/O error, with the `context` where it occurred, e.g. the line being read.
+    IoError {
+        context: String,
+        source: io::Error,
+    },
+    /// No contig of the genome build is known by the `name`.
+    UnknownContigError {
+        name: String,
+    },
+    /// The `name`, or the row of an alias table at the `line`, refers to several `contigs`.
+    AmbiguousContigError {
+        name: String,
+        contigs: Vec<String>,
+        line: Option<usize>,
+    },
+    /// The region starts after it ends.
+    InvalidRegionError {
+        start: String,
+        end: String,
+    },
+    /// The region is out of the bounds of its contig.
+    RegionOutOfBoundsError {
+        contig: String,
+        length: String,
+        start: String,
+        end: String,
+    },
+    /// The region starts or ends before the first position of its coordinate system that is
+    /// representable by its unit, e.g. a one-based start of `0u32`.
+    CoordinateOutOfBoundsError {
+        start: String,
+        end: String,
+    },
+    /// The contig has a negative length.
+    InvalidContigLengthError {
+        contig: String,
+        length: String,
+    },
+    /// The contig name is empty or consists of whitespace only, e.g. at the `line` of a FASTA index.
+    InvalidContigNameError {
+        name: String,
+        line: Option<usize>,
+    },
     Other,
 }
 
+impl SvartError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            SvartError::IllegalValueError(_) => ErrorKind::IllegalValue,
+            SvartError::ParseError { .. } => ErrorKind::Parse,
+            SvartError::RegionOutOfBoundsError { .. } => ErrorKind::OutOfBounds,
+            SvartError::CoordinateOutOfBoundsError { .. } => ErrorKind::OutOfBounds,
+            SvartError::IoError { .. } => ErrorKind::Io,
+            SvartError::UnknownContigError { .. } => ErrorKind::UnknownContig,
+            SvartError::AmbiguousContigError { .. } => ErrorKind::AmbiguousContig,
+            SvartError::InvalidRegionError { .. } => ErrorKind::InvalidRegion,
+            SvartError::InvalidContigLengthError { .. } => ErrorKind::InvalidContigLength,
+            SvartError::InvalidContigNameError { .. } => ErrorKind::InvalidContigName,
+            SvartError::Other => ErrorKind::Other,
+        }
+    }
+
+    /// Create a [`SvartError::ParseError`] for the `token` starting at the one-based `column`.
+    pub(crate) fn parse_error(message: String, token: Option<&str>, column: Option<usize>) -> Self {
+        SvartError::ParseError {
+            source_name: None,
+            line: None,
+            column,
+            token: token.map(str::to_string),
+            message,
+        }
+    }
+
+    /// Set the one-based line of the input where a [`SvartError::ParseError`],
+    /// a [`SvartError::AmbiguousContigError`] or a [`SvartError::InvalidContigNameError`]
+    /// occurred. Other errors are returned unchanged.
+    pub(crate) fn at_line(mut self, line_number: usize) -> Self {
+        match &mut self {
+            SvartError::ParseError { line, .. }
+            | SvartError::AmbiguousContigError { line, .. }
+            | SvartError::InvalidContigNameError { line, .. } => *line = Some(line_number),
+            _ => {}
+        }
+        self
+    }
+
+    /// Set the name of the input, e.g. the file name, of a [`SvartError::ParseError`].
+    /// Other errors are returned unchanged.
+    ///
+    /// ```
+    /// use rsvart::genomic::GenomeBuild;
+    /// use rsvart::SvartError;
+    ///
+    /// let report = "# Assembly name:  GRCh38.p14\n1\tassembled-molecule\t1\tChromosome\tCM000663.2\t=\tNC_000001.11\tPrimary Assembly\tx\tchr1\n";
+    /// let error = GenomeBuild::<u32>::from_assembly_report(report.as_bytes())
+    ///     .map_err(|e| e.with_source_name("GRCh38.p14_assembly_report.txt"))
+    ///     .unwrap_err();
+    /// assert!(matches!(error, SvartError::ParseError { line: Some(2), column: Some(78), .. }));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Parse error in GRCh38.p14_assembly_report.txt at line 2, column 78: Invalid sequence length 'x'"
+    /// );
+    /// ```
+    pub fn with_source_name(mut self, name: &str) -> Self {
+        if let SvartError::ParseError { source_name, .. } = &mut self {
+            *source_name = Some(name.to_string());
+        }
+        self
+    }
+}
+
 impl Display for SvartError {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match *self {
             SvartError::IllegalValueError(ref cause) => write!(f, "Illegal value error: {}", cause),
-            _ => write!(f, "Other error"),
+            SvartError::ParseError {
+                ref source_name,
+                line,
+                column,
+                ref message,
+                ..
+            } => {
+                write!(f, "Parse error")?;
+                if let Some(name) = source_name {
+                    write!(f, " in {}", name)?;
+                }
+                match (line, column) {
+                    (Some(line), Some(column)) => write!(f, " at line {}, column {}", line, column)?,
+                    (Some(line), None) => write!(f, " at line {}", line)?,
+                    (None, Some(column)) => write!(f, " at column {}", column)?,
+                    (None, None) => {}
+                }
+                write!(f, ": {}", message)
+            }
+            SvartError::IoError {
+                ref context,
+                ref source,
+            } => match context.is_empty() {
+                true => write!(f, "IO error: {}", source),
+                false => write!(f, "IO error: {}: {}", context, source),
+            },
+            SvartError::UnknownContigError { ref name } => {
+                write!(f, "Unknown contig error: No contig is named '{}'", name)
+            }
+            SvartError::AmbiguousContigError {
+                ref name,
+                ref contigs,
+                line,
+            } => {
+                write!(f, "Ambiguous contig error")?;
+                if let Some(line) = line {
+                    write!(f, " at line {}", line)?;
+                }
+                write!(f, ": '{}' matches contigs {}", name, contigs.join(", "))
+            }
+            SvartError::InvalidRegionError { ref start, ref end } => {
+                write!(f, "Invalid region error: start {} is after end {}", start, end)
+            }
+            SvartError::RegionOutOfBoundsError {
+                ref contig,
+                ref length,
+                ref start,
+                ref end,
+            } => write!(
+                f,
+                "Out of bounds error: Region {}-{} is out of the bounds of contig '{}' of length {}",
+                start, end, contig, length
+            ),
+            SvartError::CoordinateOutOfBoundsError { ref start, ref end } => write!(
+                f,
+                "Out of bounds error: Region {}-{} is before the first position of its coordinate system",
+                start, end
+            ),
+            SvartError::InvalidContigLengthError {
+                ref contig,
+                ref length,
+            } => write!(
+                f,
+                "Invalid contig length error: Contig '{}' has a negative length {}",
+                contig, length
+            ),
+            SvartError::InvalidContigNameError { ref name, line } => {
+                write!(f, "Invalid contig name error")?;
+                if let Some(line) = line {
+                    write!(f, " at line {}", line)?;
+                }
+                write!(f, ": Contig name '{}' is blank", name)
+            }
+            SvartError::Other => write!(f, "Other error"),
+        }
+    }
+}
+
+/// The I/O errors are equal if they have the same context, kind and message.
+impl PartialEq for SvartError {
+    fn eq(&self, other: &Self) -> bool {
+        use SvartError::*;
+        match (self, other) {
+            (IllegalValueError(a), IllegalValueError(b)) => a == b,
+            (
+                ParseError {
+                    source_name,
+                    line,
+                    column,
+                    token,
+                    message,
+                },
+                ParseError {
+                    source_name: other_source_name,
+                    line: other_line,
+                    column: other_column,
+                    token: other_token,
+                    message: other_message,
+                },
+            ) => {
+                source_name == other_source_name
+                    && line == other_line
+                    && column == other_column
+                    && token == other_token
+                    && message == other_message
+            }
+            (UnknownContigError { name }, UnknownContigError { name: other_name }) => {
+                name == other_name
+            }
+            (
+                AmbiguousContigError {
+                    name,
+                    contigs,
+                    line,
+                },
+                AmbiguousContigError {
+                    name: other_name,
+                    contigs: other_contigs,
+                    line: other_line,
+                },
+            ) => name == other_name && contigs == other_contigs && line == other_line,
+            (
+                IoError { context, source },
+                IoError {
+                    context: other_context,
+                    source: other_source,
+                },
+            ) => {
+                context == other_context
+                    && source.kind() == other_source.kind()
+                    && source.to_string() == other_source.to_string()
+            }
+            (
+                InvalidRegionError { start, end },
+                InvalidRegionError {
+                    start: other_start,
+                    end: other_end,
+                },
+            ) => start == other_start && end == other_end,
+            (
+                RegionOutOfBoundsError {
+                    contig,
+                    length,
+                    start,
+                    end,
+                },
+                RegionOutOfBoundsError {
+                    contig: other_contig,
+                    length: other_length,
+                    start: other_start,
+                    end: other_end,
+                },
+            ) => {
+                contig == other_contig
+                    && length == other_length
+                    && start == other_start
+                    && end == other_end
+            }
+            (
+                CoordinateOutOfBoundsError { start, end },
+                CoordinateOutOfBoundsError {
+                    start: other_start,
+                    end: other_end,
+                },
+            ) => start == other_start && end == other_end,
+            (
+                InvalidContigLengthError { contig, length },
+                InvalidContigLengthError {
+                    contig: other_contig,
+                    length: other_length,
+                },
+            ) => contig == other_contig && length == other_length,
+            (
+                InvalidContigNameError { name, line },
+                InvalidContigNameError {
+                    name: other_name,
+                    line: other_line,
+                },
+            ) => name == other_name && line == other_line,
+            (Other, Other) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Error for SvartError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SvartError::IoError { source, .. } => Some(source),
+            _ => None,
         }
     }
 }
 
-impl Error for SvartError {}
+impl From<io::Error> for SvartError {
+    fn from(source: io::Error) -> Self {
+        SvartError::IoError {
+            context: String::new(),
+            source,
+        }
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -28,8 +362,102 @@ mod test {
         SvartError::IllegalValueError("Something went wrong."),
         "Illegal value error: Something went wrong."
     )]
+    #[case(
+        SvartError::parse_error("Invalid coordinate 'x'".to_string(), Some("x"), Some(4)),
+        "Parse error at column 4: Invalid coordinate 'x'"
+    )]
+    #[case(
+        SvartError::parse_error("Invalid sequence length 'x'".to_string(), Some("x"), Some(9)).at_line(3),
+        "Parse error at line 3, column 9: Invalid sequence length 'x'"
+    )]
+    #[case(
+        SvartError::parse_error("Expected 10 columns but found 2".to_string(), None, None)
+            .at_line(3)
+            .with_source_name("report.txt"),
+        "Parse error in report.txt at line 3: Expected 10 columns but found 2"
+    )]
+    #[case(
+        SvartError::UnknownContigError { name: "chr9".to_string() },
+        "Unknown contig error: No contig is named 'chr9'"
+    )]
+    #[case(
+        SvartError::AmbiguousContigError {
+            name: "chrY".to_string(),
+            contigs: vec!["X".to_string(), "chrY".to_string()],
+            line: None,
+        }.at_line(2),
+        "Ambiguous contig error at line 2: 'chrY' matches contigs X, chrY"
+    )]
+    #[case(
+        SvartError::InvalidRegionError { start: "20".to_string(), end: "10".to_string() },
+        "Invalid region error: start 20 is after end 10"
+    )]
+    #[case(
+        SvartError::RegionOutOfBoundsError {
+            contig: "1".to_string(),
+            length: "100".to_string(),
+            start: "90".to_string(),
+            end: "110".to_string(),
+        },
+        "Out of bounds error: Region 90-110 is out of the bounds of contig '1' of length 100"
+    )]
+    #[case(
+        SvartError::CoordinateOutOfBoundsError { start: "0".to_string(), end: "10".to_string() },
+        "Out of bounds error: Region 0-10 is before the first position of its coordinate system"
+    )]
+    #[case(
+        SvartError::InvalidContigNameError { name: " ".to_string(), line: None },
+        "Invalid contig name error: Contig name ' ' is blank"
+    )]
     #[case(SvartError::Other, "Other error")]
     fn test_svart_error(#[case] input: SvartError, #[case] expected: &str) {
         assert_eq!(format!("{}", input), expected)
     }
+
+    #[rstest]
+    #[case(SvartError::IllegalValueError("x"), ErrorKind::IllegalValue)]
+    #[case(SvartError::parse_error("x".to_string(), None, None), ErrorKind::Parse)]
+    #[case(
+        SvartError::RegionOutOfBoundsError {
+            contig: "1".to_string(),
+            length: "100".to_string(),
+            start: "90".to_string(),
+            end: "110".to_string(),
+        },
+        ErrorKind::OutOfBounds
+    )]
+    #[case(SvartError::UnknownContigError { name: "x".to_string() }, ErrorKind::UnknownContig)]
+    #[case(
+        SvartError::InvalidContigLengthError { contig: "1".to_string(), length: "-1".to_string() },
+        ErrorKind::InvalidContigLength
+    )]
+    fn test_kind(#[case] input: SvartError, #[case] expected: ErrorKind) {
+        assert_eq!(input.kind(), expected)
+    }
+
+    #[rstest]
+    fn test_io_error() {
+        let error = SvartError::from(io::Error::new(io::ErrorKind::NotFound, "missing.txt"));
+        assert_eq!(error.kind(), ErrorKind::Io);
+        assert_eq!(error.to_string(), "IO error: missing.txt");
+        assert_eq!(error.source().unwrap().to_string(), "missing.txt");
+        assert_eq!(
+            error,
+            SvartError::from(io::Error::new(io::ErrorKind::NotFound, "missing.txt"))
+        );
+        assert_ne!(error, SvartError::Other);
+
+        let error = SvartError::IoError {
+            context: "Line 2".to_string(),
+            source: io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            ),
+        };
+        assert_eq!(
+            error.to_string(),
+            "IO error: Line 2: stream did not contain valid UTF-8"
+        );
+        assert!(SvartError::Other.source().is_none());
+    }
 }
@@ -1 +1,2 @@
+#[allow(dead_code)]
 mod test_data;
@@ -0,0 +1,166 @@
+use super::genome::field_column;
+use super::{AssignedMoleculeType, Contig, GenomeBuild, GenomeBuildIdentifier, SequenceRole};
+use crate::ops::Unit;
+use crate::SvartError;
+use std::io::BufRead;
+
+const ASSEMBLY_NAME: &str = "# Assembly name:";
+const N_COLUMNS: usize = 10;
+
+impl<C> GenomeBuild<C>
+where
+    C: Unit,
+{
+    /// Read a genome build from an NCBI `*_assembly_report.txt`.
+    ///
+    /// The identifier is derived from the `# Assembly name:` header, e.g. `GRCh38.p13`
+    /// is split into the `GRCh38` major assembly and the `p13` patch.
+    pub fn from_assembly_report<R>(reader: R) -> Result<Self, SvartError>
+    where
+        R: BufRead,
+    {
+        let mut id = None;
+        let mut contigs = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.map_err(|source| SvartError::IoError {
+                context: format!("Line {}", line_number),
+                source,
+            })?;
+            if let Some(name) = line.strip_prefix(ASSEMBLY_NAME) {
+                id = Some(parse_identifier(name.trim()));
+            } else if !line.starts_with('#') && !line.trim().is_empty() {
+                contigs.push(parse_contig(&line).map_err(|e| e.at_line(line_number))?);
+            }
+        }
+        let id = id.ok_or_else(|| {
+            SvartError::parse_error(format!("Missing '{}' header", ASSEMBLY_NAME), None, None)
+        })?;
+        Ok(GenomeBuild::new(id, contigs))
+    }
+}
+
+fn parse_identifier(name: &str) -> GenomeBuildIdentifier {
+    match name.rsplit_once('.') {
+        Some((major_assembly, patch))
+            if patch.starts_with('p') && patch[1..].chars().all(|c| c.is_ascii_digit()) =>
+        {
+            GenomeBuildIdentifier::new(major_assembly.to_string(), patch.to_string())
+        }
+        _ => GenomeBuildIdentifier::new(name.to_string(), "".to_string()),
+    }
+}
+
+/// Parse a tab-separated assembly report record.
+fn parse_contig<C>(line: &str) -> Result<Contig<C>, SvartError>
+where
+    C: Unit,
+{
+    let columns: Vec<_> = line.trim_end_matches(['\r', '\n']).split('\t').collect();
+    if columns.len() != N_COLUMNS {
+        return Err(SvartError::parse_error(
+            format!("Expected {} columns but found {}", N_COLUMNS, columns.len()),
+            None,
+            None,
+        ));
+    }
+    let invalid_length = || {
+        SvartError::parse_error(
+            format!("Invalid sequence length '{}'", columns[8]),
+            Some(columns[8]),
+            Some(field_column(&columns, 8)),
+        )
+    };
+    let length = columns[8]
+        .parse::<u64>()
+        .ok()
+        .and_then(C::from_u64)
+        .ok_or_else(invalid_length)?;
+    Contig::new(
+        columns[0].to_string(),
+        SequenceRole::from(columns[1]),
+        columns[2].to_string(),
+        AssignedMoleculeType::from(columns[3]),
+        length,
+        columns[4].to_string(),
+        columns[6].to_string(),
+        columns[9].to_string(),
+    )
+    .ok_or_else(invalid_length)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::genomic::{AssignedMoleculeType, GenomeBuild, SequenceRole};
+    use crate::ops::Located;
+    use crate::{ErrorKind, SvartError};
+    use rstest::rstest;
+    use std::error::Error;
+
+    const REPORT: &str = "\
+# Assembly name:  GRCh38.p13
+# Organism name:  Homo sapiens (human)
+#
+# Sequence-Name\tSequence-Role\tAssigned-Molecule\tAssigned-Molecule-Location/Type\tGenBank-Accn\tRelationship\tRefSeq-Accn\tAssembly-Unit\tSequence-Length\tUCSC-style-name
+1\tassembled-molecule\t1\tChromosome\tCM000663.2\t=\tNC_000001.11\tPrimary Assembly\t248956422\tchr1
+HSCHR1_CTG1_UNLOCALIZED\tunlocalized-scaffold\t1\tChromosome\tKI270706.1\t=\tNT_187361.1\tPrimary Assembly\t175055\tchr1_KI270706v1_random
+MT\tassembled-molecule\tMT\tMitochondrion\tJ01415.2\t=\tNC_012920.1\tnon-nuclear\t16569\tchrM
+";
+
+    #[rstest]
+    fn test_from_assembly_report() {
+        let build: GenomeBuild<u32> = GenomeBuild::from_assembly_report(REPORT.as_bytes()).unwrap();
+        assert_eq!(build.id().major_assembly(), "GRCh38");
+        assert_eq!(build.id().patch(), "p13");
+        assert_eq!(build.contigs().len(), 3);
+
+        let contig = build.contig_from_refseq("NC_012920.1").unwrap();
+        assert_eq!(contig.name(), "MT");
+        assert_eq!(contig.sequence_role(), &SequenceRole::AssembledMolecule);
+        assert_eq!(
+            contig.assigned_molecule_type(),
+            &AssignedMoleculeType::Mitochondrion
+        );
+        assert_eq!(contig.gen_bank_accession(), "J01415.2");
+        assert_eq!(contig.ucsc_name(), "chrM");
+        assert_eq!(*contig.end(), 16_569);
+
+        let contig = build.contig_from_ucsc("chr1_KI270706v1_random").unwrap();
+        assert_eq!(contig.sequence_role(), &SequenceRole::UnlocalizedScaffold);
+        assert_eq!(contig.assigned_molecule(), "1");
+    }
+
+    #[rstest]
+    #[case("# Assembly name:  T2T-CHM13v2.0\n", "T2T-CHM13v2.0", "")]
+    #[case("# Assembly name:  GRCh37.p13\r\n", "GRCh37", "p13")]
+    fn test_identifier(#[case] report: &str, #[case] major_assembly: &str, #[case] patch: &str) {
+        let build: GenomeBuild<u32> = GenomeBuild::from_assembly_report(report.as_bytes()).unwrap();
+        assert_eq!(build.id().major_assembly(), major_assembly);
+        assert_eq!(build.id().patch(), patch);
+    }
+
+    #[rstest]
+    #[case(
+        "# Assembly name:  GRCh38\n1\tassembled-molecule\t1\n",
+        "Parse error at line 2: Expected 10 columns but found 3"
+    )]
+    #[case(
+        "# Assembly name:  GRCh38\n#\n1\tassembled-molecule\t1\tChromosome\tCM000663.2\t=\tNC_000001.11\tPrimary Assembly\t300\tchr1\n",
+        "Parse error at line 3, column 78: Invalid sequence length '300'"
+    )]
+    #[case("1\tassembled-molecule\t1\tChromosome\tCM000663.2\t=\tNC_000001.11\tPrimary Assembly\t30\tchr1\n", "Parse error: Missing '# Assembly name:' header")]
+    fn test_from_assembly_report_fails(#[case] report: &str, #[case] expected: &str) {
+        let error = GenomeBuild::<u8>::from_assembly_report(report.as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Parse);
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_assembly_report_fails_on_invalid_utf8() {
+        let report: &[u8] = b"# Assembly name:  GRCh38\n\xff\n";
+        let error = GenomeBuild::<u8>::from_assembly_report(report).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Io);
+        assert!(matches!(error, SvartError::IoError { ref context, .. } if context == "Line 2"));
+        assert!(error.source().is_some());
+    }
+}
@@ -19,7 +19,7 @@ impl From<&str> for AssignedMoleculeType {
             "PLASMID" => AssignedMoleculeType::Plasmid,
             "SEGMENT" => AssignedMoleculeType::Segment,
             "LINKAGE GROUP" => AssignedMoleculeType::LinkageGroup,
-            "NA" | _ => AssignedMoleculeType::Unknown,
+            _ => AssignedMoleculeType::Unknown,
         }
     }
 }
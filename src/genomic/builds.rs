@@ -0,0 +1,132 @@
+//! Genome builds bundled with the crate, each behind its own cargo feature.
+//!
+//! The bundled GRCh37 and GRCh38 assembly reports include the assembled molecules
+//! of the builds only, i.e. the chromosomes and the mitochondrion, while T2T-CHM13v2.0
+//! consists of the assembled molecules entirely. A build with all scaffolds and patches
+//! can be read from the complete NCBI report with [`GenomeBuild::from_assembly_report`].
+use super::GenomeBuild;
+use crate::ops::Unit;
+
+#[cfg(feature = "grch37")]
+const GRCH37_P13: &str = include_str!("../../data/GRCh37.p13_assembly_report.txt");
+#[cfg(feature = "grch38")]
+const GRCH38_P13: &str = include_str!("../../data/GRCh38.p13_assembly_report.txt");
+#[cfg(feature = "grch38")]
+const GRCH38_P14: &str = include_str!("../../data/GRCh38.p14_assembly_report.txt");
+#[cfg(feature = "t2t")]
+const T2T_CHM13V2: &str = include_str!("../../data/T2T-CHM13v2.0_assembly_report.txt");
+
+impl<C> GenomeBuild<C>
+where
+    C: Unit,
+{
+    /// Get the GRCh37.p13 build.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contig lengths do not fit into `C`.
+    #[cfg(feature = "grch37")]
+    pub fn grch37() -> Self {
+        bundled(GRCH37_P13)
+    }
+
+    /// Get the latest GRCh38 build, GRCh38.p14.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contig lengths do not fit into `C`.
+    #[cfg(feature = "grch38")]
+    pub fn grch38() -> Self {
+        GenomeBuild::grch38_p14()
+    }
+
+    /// Get the GRCh38.p13 build.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contig lengths do not fit into `C`.
+    #[cfg(feature = "grch38")]
+    pub fn grch38_p13() -> Self {
+        bundled(GRCH38_P13)
+    }
+
+    /// Get the GRCh38.p14 build.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contig lengths do not fit into `C`.
+    #[cfg(feature = "grch38")]
+    pub fn grch38_p14() -> Self {
+        bundled(GRCH38_P14)
+    }
+
+    /// Get the T2T-CHM13v2.0 build.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the contig lengths do not fit into `C`.
+    #[cfg(feature = "t2t")]
+    pub fn t2t_chm13v2() -> Self {
+        bundled(T2T_CHM13V2)
+    }
+}
+
+fn bundled<C>(report: &str) -> GenomeBuild<C>
+where
+    C: Unit,
+{
+    GenomeBuild::from_assembly_report(report.as_bytes())
+        .expect("Bundled assembly report should be valid")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::genomic::GenomeBuild;
+    use crate::ops::Located;
+    use rstest::rstest;
+
+    #[cfg(feature = "grch37")]
+    #[rstest]
+    fn test_grch37() {
+        let build: GenomeBuild<u32> = GenomeBuild::grch37();
+        assert_eq!(build.id().major_assembly(), "GRCh37");
+        assert_eq!(build.id().patch(), "p13");
+        assert_eq!(build.contigs().len(), 25);
+        let contig = build.contig_from_refseq("NC_000001.10").unwrap();
+        assert_eq!(*contig.end(), 249_250_621);
+    }
+
+    #[cfg(feature = "grch38")]
+    #[rstest]
+    fn test_grch38() {
+        let build: GenomeBuild<u64> = GenomeBuild::grch38();
+        assert_eq!(build.id().patch(), "p14");
+        assert_eq!(build.contigs().len(), 25);
+        let contig = build.contig_from_ucsc("chrX").unwrap();
+        assert_eq!(contig.ref_seq_accession(), "NC_000023.11");
+        assert_eq!(*contig.end(), 156_040_895);
+    }
+
+    #[cfg(feature = "grch38")]
+    #[rstest]
+    fn test_grch38_p13() {
+        let build: GenomeBuild<u64> = GenomeBuild::grch38_p13();
+        assert_eq!(build.id().major_assembly(), "GRCh38");
+        assert_eq!(build.id().patch(), "p13");
+        assert_eq!(build.contigs().len(), 25);
+        let contig = build.contig("chrM").unwrap();
+        assert_eq!(contig.ref_seq_accession(), "NC_012920.1");
+        assert_eq!(*contig.end(), 16_569);
+    }
+
+    #[cfg(feature = "t2t")]
+    #[rstest]
+    fn test_t2t_chm13v2() {
+        let build: GenomeBuild<i64> = GenomeBuild::t2t_chm13v2();
+        assert_eq!(build.id().major_assembly(), "T2T-CHM13v2.0");
+        assert_eq!(build.contigs().len(), 25);
+        let contig = build.contig_from_genbank("CP068277.2").unwrap();
+        assert_eq!(contig.name(), "1");
+        assert_eq!(*contig.end(), 248_387_328);
+    }
+}
@@ -0,0 +1,214 @@
+use super::genome::is_alias;
+use super::GenomeBuild;
+use crate::SvartError;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// The values of the source column of the UCSC `chromAlias.txt` database table.
+const ALIAS_SOURCES: [&str; 6] = ["assembly", "custom", "ensembl", "genbank", "refseq", "ucsc"];
+
+impl<C> GenomeBuild<C> {
+    /// Attach the aliases from a UCSC `chromAlias.txt` or any other tab-separated alias table.
+    ///
+    /// Each row lists the names of a single sequence. A row is matched to a contig by the names
+    /// already known to the build, see [`GenomeBuild::contig`], and the other names of the row
+    /// are added to the [`super::Contig::aliases`]. The rows that match no contig are skipped
+    /// and the lines starting with `#` are ignored.
+    ///
+    /// The rows of the UCSC database table, i.e. `alias`, `chrom` and the comma-separated
+    /// sources of the alias such as `ensembl` or `refseq`, are recognized by the source column,
+    /// which is not added to the aliases.
+    ///
+    /// An alias matching several contigs, either within a row or across the rows of the table,
+    /// is reported as [`SvartError::AmbiguousContigError`].
+    pub fn load_aliases<R>(&mut self, reader: R) -> Result<(), SvartError>
+    where
+        R: BufRead,
+    {
+        let mut aliases = Vec::new();
+        let mut contig_of_alias: HashMap<String, usize> = HashMap::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.map_err(|source| SvartError::IoError {
+                context: format!("Line {}", line_number),
+                source,
+            })?;
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields: Vec<_> = line.split('\t').map(str::trim).collect();
+            if fields.len() == 3 && is_alias_source(fields[2]) {
+                fields.pop();
+            }
+
+            let mut matched: Option<usize> = None;
+            let mut unknown = Vec::new();
+            for name in fields.into_iter().filter(|n| is_alias(n)) {
+                match self.contig_index(name) {
+                    Ok(index) => match matched {
+                        Some(other) if other != index => {
+                            return Err(SvartError::AmbiguousContigError {
+                                name: name.to_string(),
+                                contigs: vec![
+                                    self.contigs()[other].name().to_string(),
+                                    self.contigs()[index].name().to_string(),
+                                ],
+                                line: Some(line_number),
+                            });
+                        }
+                        _ => matched = Some(index),
+                    },
+                    Err(SvartError::UnknownContigError { .. }) => unknown.push(name.to_string()),
+                    Err(e) => return Err(e.at_line(line_number)),
+                }
+            }
+            if let Some(index) = matched {
+                for alias in unknown {
+                    match contig_of_alias.get(&alias) {
+                        Some(&other) if other != index => {
+                            return Err(SvartError::AmbiguousContigError {
+                                name: alias,
+                                contigs: vec![
+                                    self.contigs()[other].name().to_string(),
+                                    self.contigs()[index].name().to_string(),
+                                ],
+                                line: Some(line_number),
+                            });
+                        }
+                        Some(_) => {}
+                        None => {
+                            contig_of_alias.insert(alias.clone(), index);
+                            aliases.push((index, alias));
+                        }
+                    }
+                }
+            }
+        }
+        self.add_aliases(aliases);
+        Ok(())
+    }
+}
+
+/// Check if the field lists the sources of an alias, e.g. `ensembl` or `genbank,refseq`.
+fn is_alias_source(field: &str) -> bool {
+    field
+        .split(',')
+        .all(|source| ALIAS_SOURCES.contains(&source))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::genomic::{
+        AssignedMoleculeType, Contig, GenomeBuild, GenomeBuildIdentifier, GenomicRegion,
+        SequenceRole,
+    };
+    use crate::{OneBased, SvartError};
+    use rstest::rstest;
+
+    fn build() -> GenomeBuild<u32> {
+        let contig = |name: &str, refseq: &str, ucsc_name: &str| {
+            Contig::new(
+                name.to_string(),
+                SequenceRole::AssembledMolecule,
+                name.to_string(),
+                AssignedMoleculeType::Chromosome,
+                1_000,
+                "na".to_string(),
+                refseq.to_string(),
+                ucsc_name.to_string(),
+            )
+            .unwrap()
+        };
+        GenomeBuild::new(
+            GenomeBuildIdentifier::new("GRCh38".to_string(), "p14".to_string()),
+            vec![
+                contig("1", "NC_000001.11", "chr1"),
+                contig("MT", "NC_012920.1", "chrM"),
+            ],
+        )
+    }
+
+    #[rstest]
+    fn test_load_aliases() {
+        let table = "\
+# ucsc\tassembly\tensembl\tgenbank\trefseq
+chr1\t1\t1\tCM000663.2\tNC_000001.11
+chrM\tMT\tMT\tJ01415.2\tNC_012920.1
+chr1_KI270706v1_random\tHSCHR1_CTG1_UNLOCALIZED\tKI270706.1\tKI270706.1\tNT_187361.1
+";
+        let mut build = build();
+        build.load_aliases(table.as_bytes()).unwrap();
+
+        let mt = build.contig("J01415.2").unwrap();
+        assert_eq!(mt.name(), "MT");
+        assert_eq!(mt.aliases(), &["J01415.2".to_string()]);
+        assert_eq!(build.contig_from_genbank("CM000663.2").unwrap().name(), "1");
+        assert!(build.contig("KI270706.1").is_err());
+
+        let region: GenomicRegion<u32, OneBased> = build.parse_region("CM000663.2:1-10").unwrap();
+        assert_eq!(region.to_string(), "1:1-10:+");
+    }
+
+    #[rstest]
+    fn test_load_aliases_from_database_table() {
+        let table = "\
+1\tchr1\tensembl
+CM000663.2\tchr1\tgenbank
+NC_000001.11\tchr1\trefseq
+MT\tchrM\tensembl
+J01415.2\tchrM\tgenbank,refseq
+";
+        let mut build = build();
+        build.load_aliases(table.as_bytes()).unwrap();
+
+        assert_eq!(build.contig("CM000663.2").unwrap().name(), "1");
+        assert_eq!(
+            build.contig("1").unwrap().aliases(),
+            &["CM000663.2".to_string()]
+        );
+        assert_eq!(
+            build.contig("MT").unwrap().aliases(),
+            &["J01415.2".to_string()]
+        );
+        assert!(build.contig("ensembl").is_err());
+        assert!(build.contig("genbank,refseq").is_err());
+    }
+
+    #[rstest]
+    fn test_load_aliases_fails_on_conflict_across_rows() {
+        let mut build = build();
+        let error = build
+            .load_aliases("chr1\tchromosome_1\nchrM\tchromosome_1\n".as_bytes())
+            .unwrap_err();
+        assert_eq!(
+            error,
+            SvartError::AmbiguousContigError {
+                name: "chromosome_1".to_string(),
+                contigs: vec!["1".to_string(), "MT".to_string()],
+                line: Some(2),
+            }
+        );
+        assert!(build.contig("chromosome_1").is_err());
+    }
+
+    #[rstest]
+    fn test_load_aliases_fails_on_conflict() {
+        let mut build = build();
+        let error = build
+            .load_aliases("#\nchr1\tchrM\n".as_bytes())
+            .unwrap_err();
+        assert_eq!(
+            error,
+            SvartError::AmbiguousContigError {
+                name: "chrM".to_string(),
+                contigs: vec!["1".to_string(), "MT".to_string()],
+                line: Some(2),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Ambiguous contig error at line 2: 'chrM' matches contigs 1, MT"
+        );
+    }
+}
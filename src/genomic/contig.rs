@@ -1,8 +1,21 @@
-use crate::genomic::{AssignedMoleculeType, SequenceRole};
+use crate::genomic::{
+    AssignedMoleculeType, ContigBuilder, SequenceRole, Strand, Topology, Windows,
+};
 use crate::ops::{Located, Unit};
+use crate::{CoordinateSystemMarker, SvartError, ZeroBased};
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Clone, Ord)]
+/// A sequence of a genome build.
+///
+/// The contigs are identified by their name, length and accessions, so that the extra
+/// [`Contig::aliases`] and the position in a genome build do not affect the equality and
+/// the hash of a contig. The contigs are ordered by the same fields, consistently with the
+/// equality; use [`super::GenomeBuild::sort_contigs`] to order them as in a genome build.
+#[derive(Debug, Clone)]
 pub struct Contig<C> {
+    ordinal: usize,
     name: String,
     sequence_role: SequenceRole,
     assigned_molecule: String,
@@ -12,9 +25,20 @@ pub struct Contig<C> {
     ucsc_name: String,
     start: C,
     end: C,
+    topology: Topology,
+    aliases: Vec<String>,
 }
 
 impl<C> Contig<C> {
+    /// Get the position of the contig in its [`super::GenomeBuild`].
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    pub(crate) fn set_ordinal(&mut self, ordinal: usize) {
+        self.ordinal = ordinal;
+    }
+
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
@@ -42,12 +66,72 @@ impl<C> Contig<C> {
     pub fn ucsc_name(&self) -> &str {
         self.ucsc_name.as_str()
     }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn is_circular(&self) -> bool {
+        self.topology == Topology::Circular
+    }
+
+    /// Override the topology inferred from the [`AssignedMoleculeType`] of the contig.
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    /// Get the fields identifying the contig.
+    fn identity(&self) -> (&str, &C, &C, &str, &str, &str) {
+        (
+            &self.name,
+            &self.start,
+            &self.end,
+            &self.gen_bank_accession,
+            &self.ref_seq_accession,
+            &self.ucsc_name,
+        )
+    }
+
+    /// Check if the contigs agree in all fields, including the ones ignored by the equality.
+    pub(crate) fn is_identical(&self, other: &Self) -> bool
+    where
+        C: PartialEq,
+    {
+        self == other
+            && self.ordinal == other.ordinal
+            && self.sequence_role == other.sequence_role
+            && self.assigned_molecule == other.assigned_molecule
+            && self.assigned_molecule_type == other.assigned_molecule_type
+            && self.topology == other.topology
+            && self.aliases == other.aliases
+    }
+
+    /// Get the extra names of the contig, such as Ensembl names or lab-specific aliases.
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// Add an extra name of the contig, unless the contig already has the alias.
+    pub fn add_alias(&mut self, alias: String) {
+        if !self.aliases.contains(&alias) {
+            self.aliases.push(alias);
+        }
+    }
 }
 
 impl<C> Contig<C>
 where
     C: Unit,
 {
+    /// Start building a contig, see [`ContigBuilder`].
+    pub fn builder(name: String, length: C) -> ContigBuilder<C> {
+        ContigBuilder::new(name, length)
+    }
+
+    /// Create a contig of the given `length`, or `None` if the length is negative.
+    ///
+    /// The [`Topology`] is inferred from the `assigned_molecule_type`.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         sequence_role: SequenceRole,
@@ -62,6 +146,7 @@ where
             None
         } else {
             Some(Self {
+                ordinal: 0,
                 name,
                 sequence_role,
                 assigned_molecule,
@@ -71,15 +156,108 @@ where
                 ucsc_name,
                 start: C::zero(),
                 end: length,
+                topology: Topology::from(&assigned_molecule_type),
+                aliases: vec![],
             })
         }
     }
+
+    /// Create a contig like [`Contig::new`], reporting the name and the length of the contig
+    /// if the length is negative.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        name: String,
+        sequence_role: SequenceRole,
+        assigned_molecule: String,
+        assigned_molecule_type: AssignedMoleculeType,
+        length: C,
+        gen_bank_accession: String,
+        ref_seq_accession: String,
+        ucsc_name: String,
+    ) -> Result<Self, SvartError>
+    where
+        C: Display,
+    {
+        if length < C::zero() {
+            return Err(SvartError::InvalidContigLengthError {
+                contig: name,
+                length: length.to_string(),
+            });
+        }
+        Ok(Contig::new(
+            name,
+            sequence_role,
+            assigned_molecule,
+            assigned_molecule_type,
+            length,
+            gen_bank_accession,
+            ref_seq_accession,
+            ucsc_name,
+        )
+        .expect("Contig length should be checked"))
+    }
+
+    /// Tile the contig with windows on the forward strand. See [`Windows`] for details.
+    pub fn windows<S>(&self, size: C, step: C, keep_trailing: bool) -> Windows<'_, C, S>
+    where
+        S: CoordinateSystemMarker,
+    {
+        Windows::new(
+            self,
+            Strand::Forward,
+            self.start,
+            self.end,
+            size,
+            step,
+            keep_trailing,
+        )
+    }
+}
+
+impl<C> PartialEq for Contig<C>
+where
+    C: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl<C> Eq for Contig<C> where C: Eq {}
+
+impl<C> PartialOrd for Contig<C>
+where
+    C: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for Contig<C>
+where
+    C: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.identity().cmp(&other.identity())
+    }
+}
+
+impl<C> Hash for Contig<C>
+where
+    C: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity().hash(state);
+    }
 }
 
 impl<C> Located<C> for Contig<C>
 where
     C: Unit,
 {
+    type System = ZeroBased;
+
     fn start(&self) -> &C {
         &self.start
     }
@@ -92,6 +270,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::collections::hash_map::DefaultHasher;
 
     #[test]
     fn test_default_contig() {
@@ -109,6 +288,27 @@ mod test {
         assert_eq!(contig.gen_bank_accession(), "CM000663.1");
         assert_eq!(contig.ref_seq_accession(), "NC_000001.10");
         assert_eq!(contig.ucsc_name(), "chr1");
+        assert!(!contig.is_circular());
+    }
+
+    #[test]
+    fn test_topology() {
+        let mut contig: Contig<u32> = Contig::new(
+            "MT".to_string(),
+            SequenceRole::AssembledMolecule,
+            "MT".to_string(),
+            AssignedMoleculeType::Mitochondrion,
+            16_569,
+            "J01415.2".to_string(),
+            "NC_012920.1".to_string(),
+            "chrM".to_string(),
+        )
+        .unwrap();
+        assert_eq!(contig.topology(), Topology::Circular);
+        assert!(contig.is_circular());
+
+        contig.set_topology(Topology::Linear);
+        assert!(!contig.is_circular());
     }
 
     #[test]
@@ -118,6 +318,27 @@ mod test {
         assert_eq!(one, two);
     }
 
+    #[test]
+    fn test_eq_ignores_aliases_and_ordinal() {
+        let one = get_contig().unwrap();
+        let mut two = get_contig().unwrap();
+        two.add_alias("NC_000001".to_string());
+        assert_eq!(one, two);
+        assert_eq!(one.cmp(&two), Ordering::Equal);
+
+        let hash = |contig: &Contig<u32>| {
+            let mut hasher = DefaultHasher::new();
+            contig.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&one), hash(&two));
+
+        two.set_ordinal(3);
+        assert_eq!(one, two);
+        assert_eq!(hash(&one), hash(&two));
+        assert_eq!(one.cmp(&two), Ordering::Equal);
+    }
+
     #[test]
     fn test_cmp() {
         let one = get_contig().unwrap();
@@ -149,4 +370,38 @@ mod test {
             "chr1".to_string(),
         )
     }
+
+    #[test]
+    fn test_negative_length() {
+        let contig: Option<Contig<i32>> = Contig::new(
+            "1".to_string(),
+            SequenceRole::AssembledMolecule,
+            "1".to_string(),
+            AssignedMoleculeType::Chromosome,
+            -1,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        );
+        assert!(contig.is_none());
+    }
+
+    #[test]
+    fn test_try_new_negative_length() {
+        let error = Contig::<i32>::try_new(
+            "1".to_string(),
+            SequenceRole::AssembledMolecule,
+            "1".to_string(),
+            AssignedMoleculeType::Chromosome,
+            -1,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid contig length error: Contig '1' has a negative length -1"
+        );
+    }
 }
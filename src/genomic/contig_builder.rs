@@ -0,0 +1,187 @@
+use super::{AssignedMoleculeType, Contig, SequenceRole, Topology};
+use crate::ops::Unit;
+use crate::SvartError;
+use std::fmt::Display;
+
+/// A builder of a [`Contig`] with named setters.
+///
+/// The sequence role and the assigned molecule type are unknown, the assigned molecule
+/// and the accessions are empty and the topology is inferred from the assigned molecule type,
+/// unless set otherwise.
+#[derive(Debug, Clone)]
+pub struct ContigBuilder<C> {
+    name: String,
+    length: C,
+    sequence_role: SequenceRole,
+    assigned_molecule: String,
+    assigned_molecule_type: AssignedMoleculeType,
+    gen_bank_accession: String,
+    ref_seq_accession: String,
+    ucsc_name: String,
+    topology: Option<Topology>,
+    aliases: Vec<String>,
+}
+
+impl<C> ContigBuilder<C>
+where
+    C: Unit,
+{
+    pub fn new(name: String, length: C) -> Self {
+        ContigBuilder {
+            name,
+            length,
+            sequence_role: SequenceRole::Unknown,
+            assigned_molecule: String::new(),
+            assigned_molecule_type: AssignedMoleculeType::Unknown,
+            gen_bank_accession: String::new(),
+            ref_seq_accession: String::new(),
+            ucsc_name: String::new(),
+            topology: None,
+            aliases: vec![],
+        }
+    }
+
+    pub fn sequence_role(mut self, sequence_role: SequenceRole) -> Self {
+        self.sequence_role = sequence_role;
+        self
+    }
+
+    pub fn assigned_molecule(mut self, assigned_molecule: String) -> Self {
+        self.assigned_molecule = assigned_molecule;
+        self
+    }
+
+    pub fn assigned_molecule_type(mut self, assigned_molecule_type: AssignedMoleculeType) -> Self {
+        self.assigned_molecule_type = assigned_molecule_type;
+        self
+    }
+
+    pub fn gen_bank_accession(mut self, gen_bank_accession: String) -> Self {
+        self.gen_bank_accession = gen_bank_accession;
+        self
+    }
+
+    pub fn ref_seq_accession(mut self, ref_seq_accession: String) -> Self {
+        self.ref_seq_accession = ref_seq_accession;
+        self
+    }
+
+    pub fn ucsc_name(mut self, ucsc_name: String) -> Self {
+        self.ucsc_name = ucsc_name;
+        self
+    }
+
+    pub fn topology(mut self, topology: Topology) -> Self {
+        self.topology = Some(topology);
+        self
+    }
+
+    /// Add an extra name of the contig, see [`Contig::aliases`].
+    pub fn alias(mut self, alias: String) -> Self {
+        self.aliases.push(alias);
+        self
+    }
+
+    /// Build the contig, failing if the name is empty or the length is negative.
+    pub fn build(self) -> Result<Contig<C>, SvartError>
+    where
+        C: Display,
+    {
+        if self.name.trim().is_empty() {
+            return Err(SvartError::InvalidContigNameError {
+                name: self.name,
+                line: None,
+            });
+        }
+        let mut contig = Contig::try_new(
+            self.name,
+            self.sequence_role,
+            self.assigned_molecule,
+            self.assigned_molecule_type,
+            self.length,
+            self.gen_bank_accession,
+            self.ref_seq_accession,
+            self.ucsc_name,
+        )?;
+        if let Some(topology) = self.topology {
+            contig.set_topology(topology);
+        }
+        for alias in self.aliases {
+            contig.add_alias(alias);
+        }
+        Ok(contig)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ContigBuilder;
+    use crate::genomic::{AssignedMoleculeType, Contig, SequenceRole, Topology};
+    use crate::ops::Located;
+    use crate::{ErrorKind, SvartError};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_build() {
+        let contig: Contig<u32> = ContigBuilder::new("MT".to_string(), 16_569)
+            .sequence_role(SequenceRole::AssembledMolecule)
+            .assigned_molecule("MT".to_string())
+            .assigned_molecule_type(AssignedMoleculeType::Mitochondrion)
+            .gen_bank_accession("J01415.2".to_string())
+            .ref_seq_accession("NC_012920.1".to_string())
+            .ucsc_name("chrM".to_string())
+            .alias("M".to_string())
+            .alias("M".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(
+            contig,
+            Contig::new(
+                "MT".to_string(),
+                SequenceRole::AssembledMolecule,
+                "MT".to_string(),
+                AssignedMoleculeType::Mitochondrion,
+                16_569,
+                "J01415.2".to_string(),
+                "NC_012920.1".to_string(),
+                "chrM".to_string(),
+            )
+            .map(|mut contig| {
+                contig.add_alias("M".to_string());
+                contig
+            })
+            .unwrap()
+        );
+        assert!(contig.is_circular());
+    }
+
+    #[rstest]
+    fn test_build_defaults() {
+        let contig = Contig::builder("scaffold_1".to_string(), 1_000u32)
+            .topology(Topology::Circular)
+            .build()
+            .unwrap();
+        assert_eq!(contig.sequence_role(), &SequenceRole::Unknown);
+        assert_eq!(
+            contig.assigned_molecule_type(),
+            &AssignedMoleculeType::Unknown
+        );
+        assert_eq!(contig.gen_bank_accession(), "");
+        assert_eq!(contig.ref_seq_accession(), "");
+        assert_eq!(contig.ucsc_name(), "");
+        assert!(contig.aliases().is_empty());
+        assert!(contig.is_circular());
+        assert_eq!(*contig.end(), 1_000);
+    }
+
+    #[rstest]
+    #[case("", 10, ErrorKind::InvalidContigName)]
+    #[case(" \t", 10, ErrorKind::InvalidContigName)]
+    #[case("1", -10, ErrorKind::InvalidContigLength)]
+    fn test_build_fails(#[case] name: &str, #[case] length: i32, #[case] expected: ErrorKind) {
+        let error: SvartError = ContigBuilder::new(name.to_string(), length)
+            .build()
+            .unwrap_err();
+        assert_eq!(error.kind(), expected);
+    }
+}
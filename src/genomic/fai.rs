@@ -0,0 +1,358 @@
+use super::genome::field_column;
+use super::{
+    AssignedMoleculeType, Contig, ContigBuilder, GenomeBuild, GenomeBuildIdentifier, SequenceRole,
+};
+use crate::ops::{Located, Unit};
+use crate::SvartError;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::BufRead;
+
+/// The name, length, offset, line bases and line width columns of a FASTA index.
+const MIN_COLUMNS: usize = 5;
+
+impl<C> GenomeBuild<C>
+where
+    C: Unit + Display,
+{
+    /// Read a genome build from a FASTA index (`.fai`).
+    ///
+    /// A sequence that matches a contig of the `reference` build by name, see
+    /// [`GenomeBuild::contig`], and by length takes the sequence role, the assigned molecule
+    /// and the accessions of the contig, and the reference contig name becomes an alias.
+    /// Otherwise, the sequence role and the assigned molecule are inferred from the sequence
+    /// name, e.g. `chrM` is a mitochondrion and `chr1_KI270706v1_random` is an unlocalized
+    /// scaffold of chromosome `1`.
+    pub fn from_fai<R>(
+        id: GenomeBuildIdentifier,
+        reader: R,
+        reference: Option<&GenomeBuild<C>>,
+    ) -> Result<Self, SvartError>
+    where
+        R: BufRead,
+    {
+        let mut names = HashSet::new();
+        let mut contigs = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.map_err(|source| SvartError::IoError {
+                context: format!("Line {}", line_number),
+                source,
+            })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (name, length) = parse_record::<C>(&line).map_err(|e| e.at_line(line_number))?;
+            if !names.insert(name.to_string()) {
+                let message = format!("Duplicate sequence name '{}'", name);
+                return Err(
+                    SvartError::parse_error(message, Some(name), Some(1)).at_line(line_number)
+                );
+            }
+            let matched = reference
+                .and_then(|build| build.contig(name).ok())
+                .filter(|contig| *contig.end() == length);
+            let contig = match matched {
+                Some(contig) => cross_reference(name, contig),
+                None => infer(name, length),
+            };
+            contigs.push(contig.map_err(|e| e.at_line(line_number))?);
+        }
+        Ok(GenomeBuild::new(id, contigs))
+    }
+}
+
+/// Parse the name and the length of a tab-separated FASTA index record.
+fn parse_record<C>(line: &str) -> Result<(&str, C), SvartError>
+where
+    C: Unit,
+{
+    let columns: Vec<_> = line.trim_end_matches(['\r', '\n']).split('\t').collect();
+    if columns.len() < MIN_COLUMNS {
+        return Err(SvartError::parse_error(
+            format!(
+                "Expected at least {} columns but found {}",
+                MIN_COLUMNS,
+                columns.len()
+            ),
+            None,
+            None,
+        ));
+    }
+    let length = columns[1]
+        .parse::<u64>()
+        .ok()
+        .and_then(C::from_u64)
+        .ok_or_else(|| {
+            SvartError::parse_error(
+                format!("Invalid sequence length '{}'", columns[1]),
+                Some(columns[1]),
+                Some(field_column(&columns, 1)),
+            )
+        })?;
+    Ok((columns[0], length))
+}
+
+/// Copy the metadata of the matching `contig` of a reference build to the sequence `name`.
+fn cross_reference<C>(name: &str, contig: &Contig<C>) -> Result<Contig<C>, SvartError>
+where
+    C: Unit + Display,
+{
+    let mut builder = ContigBuilder::new(name.to_string(), *contig.end())
+        .sequence_role(*contig.sequence_role())
+        .assigned_molecule(contig.assigned_molecule().to_string())
+        .assigned_molecule_type(*contig.assigned_molecule_type())
+        .gen_bank_accession(contig.gen_bank_accession().to_string())
+        .ref_seq_accession(contig.ref_seq_accession().to_string())
+        .ucsc_name(contig.ucsc_name().to_string())
+        .topology(contig.topology());
+    if contig.name() != name {
+        builder = builder.alias(contig.name().to_string());
+    }
+    for alias in contig.aliases() {
+        builder = builder.alias(alias.clone());
+    }
+    builder.build()
+}
+
+/// Infer the sequence role and the assigned molecule from the UCSC-style sequence `name`.
+fn infer<C>(name: &str, length: C) -> Result<Contig<C>, SvartError>
+where
+    C: Unit + Display,
+{
+    let stripped = name
+        .strip_prefix("chr")
+        .or_else(|| name.strip_prefix("Chr"))
+        .unwrap_or(name);
+    let molecule = stripped.split('_').next().unwrap_or(stripped);
+    let (sequence_role, molecule) = if molecule == "Un" {
+        (SequenceRole::UnplacedScaffold, "na")
+    } else if stripped.ends_with("_random") {
+        (SequenceRole::UnlocalizedScaffold, molecule)
+    } else if stripped.ends_with("_alt") {
+        (SequenceRole::AltScaffold, molecule)
+    } else if stripped.ends_with("_fix") {
+        (SequenceRole::FixPatch, molecule)
+    } else if is_primary_molecule(stripped) {
+        (SequenceRole::AssembledMolecule, molecule)
+    } else {
+        (SequenceRole::Unknown, "na")
+    };
+    let (molecule, assigned_molecule_type) = match molecule {
+        "M" | "MT" => ("MT", AssignedMoleculeType::Mitochondrion),
+        "na" => ("na", AssignedMoleculeType::Unknown),
+        _ => (molecule, AssignedMoleculeType::Chromosome),
+    };
+    let mut builder = ContigBuilder::new(name.to_string(), length)
+        .sequence_role(sequence_role)
+        .assigned_molecule(molecule.to_string())
+        .assigned_molecule_type(assigned_molecule_type);
+    if stripped != name {
+        builder = builder.ucsc_name(name.to_string());
+    }
+    builder.build()
+}
+
+/// Check if the name, without the `chr` prefix, is a numbered or sex chromosome or
+/// the mitochondrion.
+fn is_primary_molecule(name: &str) -> bool {
+    matches!(name, "X" | "Y" | "W" | "Z" | "M" | "MT")
+        || (!name.is_empty() && name.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::genomic::{
+        AssignedMoleculeType, Contig, GenomeBuild, GenomeBuildIdentifier, SequenceRole,
+    };
+    use crate::ops::Located;
+    use crate::{ErrorKind, SvartError};
+    use rstest::rstest;
+
+    const FAI: &str = "\
+chr1\t248956422\t112\t70\t71
+chr1_KI270706v1_random\t175055\t252513167\t70\t71
+chrUn_KI270302v1\t2274\t254419022\t70\t71
+chr1_KN196472v1_fix\t186494\t254421433\t70\t71
+chr6_GL000250v2_alt\t4672374\t254611014\t70\t71
+chrM\t16569\t259351934\t70\t71
+HLA-A*01:01:01:01\t3503\t259368876\t60\t61
+";
+
+    fn id() -> GenomeBuildIdentifier {
+        GenomeBuildIdentifier::new("hg38".to_string(), "".to_string())
+    }
+
+    #[rstest]
+    #[case(
+        "chr1",
+        SequenceRole::AssembledMolecule,
+        "1",
+        AssignedMoleculeType::Chromosome
+    )]
+    #[case(
+        "chr1_KI270706v1_random",
+        SequenceRole::UnlocalizedScaffold,
+        "1",
+        AssignedMoleculeType::Chromosome
+    )]
+    #[case(
+        "chrUn_KI270302v1",
+        SequenceRole::UnplacedScaffold,
+        "na",
+        AssignedMoleculeType::Unknown
+    )]
+    #[case(
+        "chr1_KN196472v1_fix",
+        SequenceRole::FixPatch,
+        "1",
+        AssignedMoleculeType::Chromosome
+    )]
+    #[case(
+        "chr6_GL000250v2_alt",
+        SequenceRole::AltScaffold,
+        "6",
+        AssignedMoleculeType::Chromosome
+    )]
+    #[case(
+        "chrM",
+        SequenceRole::AssembledMolecule,
+        "MT",
+        AssignedMoleculeType::Mitochondrion
+    )]
+    #[case(
+        "HLA-A*01:01:01:01",
+        SequenceRole::Unknown,
+        "na",
+        AssignedMoleculeType::Unknown
+    )]
+    fn test_from_fai(
+        #[case] name: &str,
+        #[case] sequence_role: SequenceRole,
+        #[case] assigned_molecule: &str,
+        #[case] assigned_molecule_type: AssignedMoleculeType,
+    ) {
+        let build: GenomeBuild<u32> = GenomeBuild::from_fai(id(), FAI.as_bytes(), None).unwrap();
+        assert_eq!(build.contigs().len(), 7);
+
+        let contig = build.contig(name).unwrap();
+        assert_eq!(contig.sequence_role(), &sequence_role);
+        assert_eq!(contig.assigned_molecule(), assigned_molecule);
+        assert_eq!(contig.assigned_molecule_type(), &assigned_molecule_type);
+    }
+
+    #[rstest]
+    fn test_from_fai_inferred_contigs() {
+        let build: GenomeBuild<u32> = GenomeBuild::from_fai(id(), FAI.as_bytes(), None).unwrap();
+        let mt = build.contig("chrM").unwrap();
+        assert_eq!(*mt.end(), 16_569);
+        assert_eq!(mt.ucsc_name(), "chrM");
+        assert_eq!(mt.gen_bank_accession(), "");
+        assert!(mt.is_circular());
+        assert_eq!(build.contig("HLA-A*01:01:01:01").unwrap().ucsc_name(), "");
+    }
+
+    #[rstest]
+    fn test_from_fai_with_reference() {
+        let contig =
+            |name: &str, molecule_type, length, genbank: &str, refseq: &str, ucsc: &str| {
+                Contig::new(
+                    name.to_string(),
+                    SequenceRole::AssembledMolecule,
+                    name.to_string(),
+                    molecule_type,
+                    length,
+                    genbank.to_string(),
+                    refseq.to_string(),
+                    ucsc.to_string(),
+                )
+                .unwrap()
+            };
+        let reference = GenomeBuild::new(
+            GenomeBuildIdentifier::new("GRCh38".to_string(), "p14".to_string()),
+            vec![
+                contig(
+                    "1",
+                    AssignedMoleculeType::Chromosome,
+                    248_956_422,
+                    "CM000663.2",
+                    "NC_000001.11",
+                    "chr1",
+                ),
+                contig(
+                    "2",
+                    AssignedMoleculeType::Chromosome,
+                    242_193_529,
+                    "CM000664.2",
+                    "NC_000002.12",
+                    "chr2",
+                ),
+                contig(
+                    "MT",
+                    AssignedMoleculeType::Mitochondrion,
+                    16_569,
+                    "J01415.2",
+                    "NC_012920.1",
+                    "chrM",
+                ),
+            ],
+        );
+        let fai = "chr1\t248956422\t112\t70\t71\nchr2\t1000\t0\t70\t71\nchrM\t16569\t0\t70\t71\n";
+        let build: GenomeBuild<u32> =
+            GenomeBuild::from_fai(id(), fai.as_bytes(), Some(&reference)).unwrap();
+
+        let one = build.contig("NC_000001.11").unwrap();
+        assert_eq!(one.name(), "chr1");
+        assert_eq!(one.gen_bank_accession(), "CM000663.2");
+        assert_eq!(one.aliases(), &["1".to_string()]);
+        assert_eq!(build.contig("1").unwrap().name(), "chr1");
+
+        // The length differs from the reference contig.
+        let two = build.contig("chr2").unwrap();
+        assert_eq!(two.ref_seq_accession(), "");
+        assert_eq!(two.sequence_role(), &SequenceRole::AssembledMolecule);
+
+        let mt = build.contig("J01415.2").unwrap();
+        assert_eq!(mt.name(), "chrM");
+        assert!(mt.is_circular());
+    }
+
+    #[rstest]
+    #[case(
+        "chr1\t248956422\n",
+        "Parse error at line 1: Expected at least 5 columns but found 2"
+    )]
+    #[case(
+        "chr1\t10\t0\t70\t71\n\nchr2\tten\t0\t70\t71\n",
+        "Parse error at line 3, column 6: Invalid sequence length 'ten'"
+    )]
+    #[case(
+        "chr1\t10\t0\t70\t71\nchr1\t10\t0\t70\t71\n",
+        "Parse error at line 2, column 1: Duplicate sequence name 'chr1'"
+    )]
+    fn test_from_fai_fails(#[case] fai: &str, #[case] expected: &str) {
+        let error: SvartError =
+            GenomeBuild::<u32>::from_fai(id(), fai.as_bytes(), None).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Parse);
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_fai_fails_on_blank_name() {
+        let fai = "chr1\t10\t0\t70\t71\n\t10\t0\t70\t71\n";
+        let error: SvartError =
+            GenomeBuild::<u32>::from_fai(id(), fai.as_bytes(), None).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidContigName);
+        assert_eq!(
+            error,
+            SvartError::InvalidContigNameError {
+                name: "".to_string(),
+                line: Some(2),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Invalid contig name error at line 2: Contig name '' is blank"
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use super::Contig;
+use super::{Contig, Contiged, GenomicRegion, Strand, Stranded};
+use crate::ops::{zero_based_segments_on_strand, Located, Unit};
+use crate::region::{parse_coordinate, parse_range};
+use crate::{CoordinateSystemMarker, OneBased, SvartError};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GenomeBuildIdentifier {
@@ -22,16 +28,87 @@ impl GenomeBuildIdentifier {
     }
 }
 
+/// Order of the contigs in a [`GenomeBuild`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ContigOrder {
+    /// Chromosomes in the numeric order followed by `X`, `Y`, the mitochondrion
+    /// and the other contigs in the natural order of their names, e.g. `1, 2, 10, X, Y, MT`.
+    Karyotypic,
+    /// Contigs in the lexicographic order of their names, e.g. `1, 10, 2, MT, X, Y`.
+    Lexicographic,
+}
+
+/// A genome build with contigs in the input order, unless sorted with [`GenomeBuild::sort_contigs`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenomeBuild<C> {
     id: GenomeBuildIdentifier,
     contigs: Vec<Contig<C>>,
+    /// Indices of the contigs known under each name, accession, UCSC name and custom alias.
+    names: HashMap<String, Vec<usize>>,
+    gen_bank_accessions: HashMap<String, Vec<usize>>,
+    ref_seq_accessions: HashMap<String, Vec<usize>>,
+    ucsc_names: HashMap<String, Vec<usize>>,
+    custom_aliases: HashMap<String, Vec<usize>>,
+    /// Indices of the contigs known under each alias.
+    aliases: HashMap<String, Vec<usize>>,
 }
 
 impl<C> GenomeBuild<C> {
-    pub fn new(id: GenomeBuildIdentifier, mut contigs: Vec<Contig<C>>) -> Self {
-        contigs.sort_by(|l, r| l.name().cmp(r.name()));
-        GenomeBuild { id, contigs }
+    pub fn new(id: GenomeBuildIdentifier, contigs: Vec<Contig<C>>) -> Self {
+        let mut build = GenomeBuild {
+            id,
+            contigs,
+            names: HashMap::new(),
+            gen_bank_accessions: HashMap::new(),
+            ref_seq_accessions: HashMap::new(),
+            ucsc_names: HashMap::new(),
+            custom_aliases: HashMap::new(),
+            aliases: HashMap::new(),
+        };
+        build.reindex();
+        build
+    }
+
+    /// Sort the contigs and update their [`Contig::ordinal`]s.
+    pub fn sort_contigs(&mut self, order: ContigOrder) {
+        match order {
+            ContigOrder::Karyotypic => self
+                .contigs
+                .sort_by_cached_key(|c| karyotypic_key(c.name())),
+            ContigOrder::Lexicographic => self.contigs.sort_by(|l, r| l.name().cmp(r.name())),
+        }
+        self.reindex();
+    }
+
+    /// Update the ordinals of the contigs and index their names.
+    fn reindex(&mut self) {
+        for (i, contig) in self.contigs.iter_mut().enumerate() {
+            contig.set_ordinal(i);
+        }
+        self.names.clear();
+        self.gen_bank_accessions.clear();
+        self.ref_seq_accessions.clear();
+        self.ucsc_names.clear();
+        self.custom_aliases.clear();
+        self.aliases.clear();
+        self.index_aliases();
+    }
+
+    fn index_aliases(&mut self) {
+        for (i, contig) in self.contigs.iter().enumerate() {
+            let names = [
+                (&mut self.names, contig.name()),
+                (&mut self.gen_bank_accessions, contig.gen_bank_accession()),
+                (&mut self.ref_seq_accessions, contig.ref_seq_accession()),
+                (&mut self.ucsc_names, contig.ucsc_name()),
+            ];
+            for (index, alias) in names {
+                index_alias(index, &mut self.aliases, alias, i);
+            }
+            for alias in contig.aliases() {
+                index_alias(&mut self.custom_aliases, &mut self.aliases, alias, i);
+            }
+        }
     }
 
     pub fn id(&self) -> &GenomeBuildIdentifier {
@@ -42,30 +119,310 @@ impl<C> GenomeBuild<C> {
         &self.contigs
     }
 
-    pub fn contig_from_genbank(&self, name: &str) -> Option<&Contig<C>> {
-        self.contigs
-            .iter()
-            .find(|&c| c.name().eq(name) || c.gen_bank_accession().eq(name))
+    /// Resolve a contig by any of its names, i.e. the name, GenBank or RefSeq accession,
+    /// the UCSC name or one of the [`Contig::aliases`]. The names are also matched with the `chr` prefix added or removed
+    /// if there is no exact match, e.g. `chr1` resolves to a contig named `1`.
+    ///
+    /// Returns an error if the name is unknown or shared by several contigs.
+    pub fn contig(&self, name: &str) -> Result<&Contig<C>, SvartError> {
+        self.contig_index(name).map(|i| &self.contigs[i])
     }
 
-    pub fn contig_from_refseq(&self, name: &str) -> Option<&Contig<C>> {
-        self.contigs
-            .iter()
-            .find(|&c| c.name().eq(name) || c.ref_seq_accession().eq(name))
+    /// Resolve the index of a contig in [`GenomeBuild::contigs`], see [`GenomeBuild::contig`].
+    pub(crate) fn contig_index(&self, name: &str) -> Result<usize, SvartError> {
+        let indices = match self.aliases.get(name) {
+            Some(indices) => indices,
+            None => {
+                let toggled = match name.strip_prefix("chr") {
+                    Some(stripped) => stripped.to_string(),
+                    None => format!("chr{}", name),
+                };
+                self.aliases
+                    .get(&toggled)
+                    .ok_or_else(|| SvartError::UnknownContigError {
+                        name: name.to_string(),
+                    })?
+            }
+        };
+        self.single_contig(name, indices)
+    }
+
+    /// Get the only index of the contig known by the `name` among the `indices`.
+    fn single_contig(&self, name: &str, indices: &[usize]) -> Result<usize, SvartError> {
+        match indices {
+            [] => Err(SvartError::UnknownContigError {
+                name: name.to_string(),
+            }),
+            [i] => Ok(*i),
+            _ => Err(SvartError::AmbiguousContigError {
+                name: name.to_string(),
+                contigs: indices
+                    .iter()
+                    .map(|&i| self.contigs[i].name().to_string())
+                    .collect(),
+                line: None,
+            }),
+        }
+    }
+
+    /// Get the position of the contig with the same name in this build.
+    pub(crate) fn position_of(&self, contig: &Contig<C>) -> Option<usize> {
+        self.names
+            .get(contig.name())
+            .and_then(|indices| indices.first())
+            .copied()
+    }
+
+    /// Resolve a contig by its name, GenBank accession or one of the [`Contig::aliases`].
+    ///
+    /// Returns an error if the name is unknown or shared by several contigs.
+    pub fn contig_from_genbank(&self, name: &str) -> Result<&Contig<C>, SvartError> {
+        self.lookup(&self.gen_bank_accessions, name)
+    }
+
+    /// Resolve a contig by its name, RefSeq accession or one of the [`Contig::aliases`].
+    ///
+    /// Returns an error if the name is unknown or shared by several contigs.
+    pub fn contig_from_refseq(&self, name: &str) -> Result<&Contig<C>, SvartError> {
+        self.lookup(&self.ref_seq_accessions, name)
+    }
+
+    /// Resolve a contig by its name, UCSC name or one of the [`Contig::aliases`].
+    ///
+    /// Returns an error if the name is unknown or shared by several contigs.
+    pub fn contig_from_ucsc(&self, name: &str) -> Result<&Contig<C>, SvartError> {
+        self.lookup(&self.ucsc_names, name)
+    }
+
+    /// Look the contig up by its name, by an alias from the `index` or by a custom alias.
+    fn lookup(
+        &self,
+        index: &HashMap<String, Vec<usize>>,
+        name: &str,
+    ) -> Result<&Contig<C>, SvartError> {
+        let mut indices = Vec::new();
+        for &i in [&self.names, index, &self.custom_aliases]
+            .into_iter()
+            .filter_map(|index| index.get(name))
+            .flatten()
+        {
+            if !indices.contains(&i) {
+                indices.push(i);
+            }
+        }
+        self.single_contig(name, &indices).map(|i| &self.contigs[i])
+    }
+
+    /// Attach the `aliases` to the contig with the given index and re-index the build.
+    pub(crate) fn add_aliases(&mut self, aliases: Vec<(usize, String)>) {
+        for (i, alias) in aliases {
+            self.contigs[i].add_alias(alias);
+        }
+        self.reindex();
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum NameChunk {
+    Number(u64),
+    Text(String),
+}
+
+/// Get a key sorting the contig names in the karyotypic order.
+fn karyotypic_key(name: &str) -> (u8, Vec<NameChunk>) {
+    let chromosome = name.strip_prefix("chr").unwrap_or(name);
+    let rank = match chromosome.to_uppercase().as_str() {
+        c if !c.is_empty() && c.chars().all(|d| d.is_ascii_digit()) => 0,
+        "X" => 1,
+        "Y" => 2,
+        "M" | "MT" => 3,
+        _ => 4,
+    };
+    let key = if rank == 4 { name } else { chromosome };
+    (rank, natural_key(key))
+}
+
+/// Split the `name` into numbers and text so that e.g. `chr2` sorts before `chr10`.
+fn natural_key(name: &str) -> Vec<NameChunk> {
+    let mut chunks = Vec::new();
+    let mut rest = name;
+    while let Some(first) = rest.chars().next() {
+        let is_digit = first.is_ascii_digit();
+        let end = rest
+            .find(|c: char| c.is_ascii_digit() != is_digit)
+            .unwrap_or(rest.len());
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(match chunk.parse() {
+            Ok(number) if is_digit => NameChunk::Number(number),
+            _ => NameChunk::Text(chunk.to_string()),
+        });
+        rest = tail;
+    }
+    chunks
+}
+
+fn index_alias(
+    index: &mut HashMap<String, Vec<usize>>,
+    aliases: &mut HashMap<String, Vec<usize>>,
+    alias: &str,
+    i: usize,
+) {
+    if is_alias(alias) {
+        for indices in [index, aliases] {
+            let indices = indices.entry(alias.to_string()).or_default();
+            if !indices.contains(&i) {
+                indices.push(i);
+            }
+        }
+    }
+}
+
+/// Get the one-based column where the field with the `index` starts in a tab-separated line.
+pub(crate) fn field_column(fields: &[&str], index: usize) -> usize {
+    fields[..index].iter().map(|f| f.len() + 1).sum::<usize>() + 1
+}
+
+/// NCBI assembly reports use `na` for the missing names.
+pub(crate) fn is_alias(name: &str) -> bool {
+    !name.is_empty() && name != "na"
+}
+
+impl<C> GenomeBuild<C>
+where
+    C: Unit,
+{
+    /// Resolve a region such as `chr1:1,000-2,000:+`, `NC_000001.11:g.100_200` or `chrX`.
+    ///
+    /// The contig is resolved with [`GenomeBuild::contig`].
+    /// The `start-end` coordinates are interpreted in the coordinate system `S`, while the HGVS
+    /// `g.` coordinates are always one-based. A bare contig resolves to the whole contig.
+    /// The strand is forward unless the region ends with `:-`, or `:.` for an unknown strand.
+    pub fn parse_region<S>(&self, value: &str) -> Result<GenomicRegion<'_, C, S>, SvartError>
+    where
+        C: FromStr + Display,
+        S: CoordinateSystemMarker,
+    {
+        let offset = value.len() - value.trim_start().len();
+        let value = value.trim();
+        let (rest, strand) = match value.rsplit_once(':') {
+            Some((rest, "+")) => (rest, Strand::Forward),
+            Some((rest, "-")) => (rest, Strand::Reverse),
+            Some((rest, ".")) => (rest, Strand::Unknown),
+            _ => (value, Strand::Forward),
+        };
+        match self.contig(rest) {
+            Err(SvartError::UnknownContigError { .. }) => {}
+            contig => {
+                let contig = contig?;
+                return Ok(GenomicRegion::from_zero_based(
+                    contig,
+                    *contig.start(),
+                    *contig.end(),
+                    strand,
+                ));
+            }
+        }
+
+        let (name, range) =
+            rest.rsplit_once(':')
+                .ok_or_else(|| SvartError::UnknownContigError {
+                    name: rest.to_string(),
+                })?;
+        let contig = self.contig(name)?;
+        // The one-based column of the range in the input.
+        let column = offset + name.len() + 2;
+        match range.strip_prefix("g.") {
+            Some(hgvs) => {
+                let column = column + 2;
+                let (start, end) = if hgvs.contains('_') {
+                    parse_range(hgvs, '_', column)?
+                } else {
+                    let position = parse_coordinate(hgvs, column)?;
+                    (position, position)
+                };
+                GenomicRegion::<C, OneBased>::try_new(contig, start, end, strand)
+                    .map(GenomicRegion::into_coordinate_system)
+            }
+            None => {
+                let (start, end) = parse_range(range, '-', column)?;
+                GenomicRegion::try_new(contig, start, end, strand)
+            }
+        }
     }
 
-    pub fn contig_from_ucsc(&self, name: &str) -> Option<&Contig<C>> {
+    /// Tile the contigs of the build with windows on the forward strand.
+    /// See [`super::Windows`] for details.
+    pub fn windows<'g, S>(
+        &'g self,
+        size: C,
+        step: C,
+        keep_trailing: bool,
+    ) -> impl Iterator<Item = GenomicRegion<'g, C, S>> + 'g
+    where
+        S: CoordinateSystemMarker + 'g,
+    {
         self.contigs
             .iter()
-            .find(|&c| c.name().eq(name) || c.ucsc_name().eq(name))
+            .flat_map(move |contig| contig.windows(size, step, keep_trailing))
+    }
+
+    /// Get the parts of the contigs that are not covered by any of the `regions`.
+    ///
+    /// The gaps are reported on the forward strand, in the order of the build contigs,
+    /// and the contigs with no regions are reported as a whole. The regions wrapping around
+    /// the origin of a circular contig cover both ends of the contig.
+    /// The regions located on contigs absent from the build are ignored.
+    pub fn complement<'g, 'r, S, T, I>(&'g self, regions: I) -> Vec<GenomicRegion<'g, C, S>>
+    where
+        S: CoordinateSystemMarker,
+        T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>> + 'r,
+        I: IntoIterator<Item = &'r T>,
+    {
+        let mut by_contig: HashMap<&str, Vec<(C, C)>> = HashMap::new();
+        for region in regions {
+            by_contig
+                .entry(region.contig().name())
+                .or_default()
+                .extend(zero_based_segments_on_strand(region, Strand::Forward));
+        }
+
+        let mut gaps = Vec::new();
+        for contig in &self.contigs {
+            let mut covered = by_contig.remove(contig.name()).unwrap_or_default();
+            covered.sort();
+            let mut position = *contig.start();
+            for (start, end) in covered {
+                if position < start {
+                    gaps.push(GenomicRegion::from_zero_based(
+                        contig,
+                        position,
+                        start,
+                        Strand::Forward,
+                    ));
+                }
+                position = position.max(end);
+            }
+            if position < *contig.end() {
+                gaps.push(GenomicRegion::from_zero_based(
+                    contig,
+                    position,
+                    *contig.end(),
+                    Strand::Forward,
+                ));
+            }
+        }
+        gaps
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::GenomeBuild;
     use super::GenomeBuildIdentifier;
-    use crate::genomic::{AssignedMoleculeType, Contig, SequenceRole};
+    use super::{ContigOrder, GenomeBuild};
+    use crate::genomic::Contiged;
+    use crate::genomic::{AssignedMoleculeType, Contig, GenomicRegion, SequenceRole, Strand};
+    use crate::ops::Located;
+    use crate::{ErrorKind, OneBased, SvartError, ZeroBased};
     use rstest::rstest;
 
     #[rstest]
@@ -85,16 +442,212 @@ mod test {
         assert_eq!(build.contigs().len(), 3);
         assert_eq!(
             *build.contig_from_genbank("CM000664.1").unwrap(),
-            *&build.contigs()[1]
+            build.contigs()[1]
         );
         assert_eq!(
             *build.contig_from_refseq("NC_000001.10").unwrap(),
-            *&build.contigs()[0]
+            build.contigs()[0]
+        );
+        assert_eq!(*build.contig_from_ucsc("chr3").unwrap(), build.contigs()[2]);
+    }
+
+    #[rstest]
+    fn test_complement() {
+        let id = GenomeBuildIdentifier::new("GRCh38".to_string(), "p13".to_string());
+        let build = GenomeBuild::new(id, get_few_contigs());
+        let one = &build.contigs()[0];
+        let three = &build.contigs()[2];
+        let regions: Vec<GenomicRegion<u8, ZeroBased>> = vec![
+            GenomicRegion::new(one, 30, 40, Strand::Forward).unwrap(),
+            GenomicRegion::new(one, 0, 10, Strand::Forward).unwrap(),
+            // (25, 35] on the forward strand.
+            GenomicRegion::new(one, 25, 35, Strand::Reverse).unwrap(),
+            GenomicRegion::new(three, 0, 60, Strand::Forward).unwrap(),
+        ];
+
+        let gaps: Vec<GenomicRegion<u8, ZeroBased>> = build.complement(&regions);
+        let actual: Vec<_> = gaps
+            .iter()
+            .map(|g| (g.contig().name(), *g.start(), *g.end()))
+            .collect();
+        assert_eq!(actual, vec![("1", 10, 25), ("1", 40, 60), ("2", 0, 60)]);
+
+        let gaps: Vec<GenomicRegion<u8, OneBased>> =
+            build.complement(&Vec::<GenomicRegion<u8, OneBased>>::new());
+        assert_eq!(gaps.len(), 3);
+        assert_eq!((*gaps[0].start(), *gaps[0].end()), (1, 60));
+    }
+
+    #[rstest]
+    #[case("chr1:1-10", "1:1-10:+")]
+    #[case("1:11-20:-", "1:11-20:-")]
+    #[case("chr2:1,0-2,0:+", "2:10-20:+")]
+    #[case("NC_000001.10:g.5_15", "1:5-15:+")]
+    #[case("CM000665.1:g.7", "3:7-7:+")]
+    #[case("chr3", "3:1-60:+")]
+    #[case("chr3:-", "3:1-60:-")]
+    #[case("chr3:5-10:.", "3:5-10:.")]
+    fn test_parse_region(#[case] input: &str, #[case] expected: &str) {
+        let id = GenomeBuildIdentifier::new("GRCh37".to_string(), "p13".to_string());
+        let build = GenomeBuild::new(id, get_few_contigs());
+        let region: GenomicRegion<u8, OneBased> = build.parse_region(input).unwrap();
+        assert_eq!(region.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "chr1:0-10",
+        "Out of bounds error: Region 0-10 is out of the bounds of contig '1' of length 60"
+    )]
+    #[case(
+        "chr1:g.0_10",
+        "Out of bounds error: Region 0-10 is out of the bounds of contig '1' of length 60"
+    )]
+    #[case(
+        "chr1:50-70",
+        "Out of bounds error: Region 50-70 is out of the bounds of contig '1' of length 60"
+    )]
+    #[case("chr1:20-10", "Invalid region error: start 20 is after end 10")]
+    #[case("chr1:g.5_2", "Invalid region error: start 5 is after end 2")]
+    #[case("chr9:1-10", "Unknown contig error: No contig is named 'chr9'")]
+    #[case("chr9", "Unknown contig error: No contig is named 'chr9'")]
+    #[case("chr1:a-10", "Parse error at column 6: Invalid coordinate 'a'")]
+    #[case(" chr1:10-1x", "Parse error at column 10: Invalid coordinate '1x'")]
+    #[case("chr1:g.5_x", "Parse error at column 10: Invalid coordinate 'x'")]
+    #[case(
+        "chr1:10",
+        "Parse error at column 6: Expected 'start-end' but got '10'"
+    )]
+    fn test_parse_region_fails(#[case] input: &str, #[case] expected: &str) {
+        let id = GenomeBuildIdentifier::new("GRCh37".to_string(), "p13".to_string());
+        let build = GenomeBuild::new(id, get_few_contigs());
+        let error: SvartError = build.parse_region::<OneBased>(input).unwrap_err();
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_parse_region_zero_based() {
+        let id = GenomeBuildIdentifier::new("GRCh37".to_string(), "p13".to_string());
+        let build = GenomeBuild::new(id, get_few_contigs());
+        let region: GenomicRegion<u8, ZeroBased> = build.parse_region("chr1:0-10").unwrap();
+        assert_eq!(region.to_string(), "1:0-10:+");
+        let region: GenomicRegion<u8, ZeroBased> = build.parse_region("chr1:g.1_10").unwrap();
+        assert_eq!(region.to_string(), "1:0-10:+");
+    }
+
+    #[rstest]
+    #[case("1", Ok("1"))]
+    #[case("CM000664.1", Ok("2"))]
+    #[case("NC_000003.10", Ok("3"))]
+    #[case("chr3", Ok("3"))]
+    #[case("chr2", Ok("2"))]
+    #[case("chr4", Err("Unknown contig error: No contig is named 'chr4'"))]
+    #[case("na", Err("Unknown contig error: No contig is named 'na'"))]
+    fn test_contig(#[case] name: &str, #[case] expected: Result<&str, &str>) {
+        let id = GenomeBuildIdentifier::new("GRCh38".to_string(), "p13".to_string());
+        let build = GenomeBuild::new(id, get_few_contigs());
+        let actual = build
+            .contig(name)
+            .map(|c| c.name())
+            .map_err(|e| e.to_string());
+        assert_eq!(actual, expected.map_err(str::to_string));
+    }
+
+    #[rstest]
+    fn test_contig_resolves_prefix() {
+        let contig = |name: &str, ucsc_name: &str| {
+            Contig::<u8>::new(
+                name.to_string(),
+                SequenceRole::AssembledMolecule,
+                name.to_string(),
+                AssignedMoleculeType::Chromosome,
+                10,
+                "na".to_string(),
+                "na".to_string(),
+                ucsc_name.to_string(),
+            )
+            .unwrap()
+        };
+        let id = GenomeBuildIdentifier::new("CHM13".to_string(), "".to_string());
+        let build = GenomeBuild::new(id, vec![contig("chr1", "na"), contig("X", "chrY")]);
+        assert_eq!(build.contig("1").unwrap().name(), "chr1");
+        assert_eq!(build.contig("chrX").unwrap().name(), "X");
+        assert_eq!(
+            build.contig_from_genbank("na"),
+            Err(SvartError::UnknownContigError {
+                name: "na".to_string()
+            })
+        );
+
+        // `chrY` is the UCSC name of `X` while `Y` is not known.
+        let build = GenomeBuild::new(
+            GenomeBuildIdentifier::new("CHM13".to_string(), "".to_string()),
+            vec![contig("X", "chrY"), contig("chrY", "na")],
+        );
+        assert_eq!(
+            build.contig("chrY").unwrap_err().to_string(),
+            "Ambiguous contig error: 'chrY' matches contigs X, chrY"
         );
         assert_eq!(
-            *build.contig_from_ucsc("chr3").unwrap(),
-            *&build.contigs()[2]
+            build.contig_from_ucsc("chrY").unwrap_err().kind(),
+            ErrorKind::AmbiguousContig
         );
+        assert_eq!(build.contig_from_genbank("chrY").unwrap().name(), "chrY");
+    }
+
+    #[rstest]
+    #[case(
+        ContigOrder::Karyotypic,
+        vec!["1", "2", "10", "X", "Y", "MT", "HSCHR1_CTG1_UNLOCALIZED", "HSCHR1_CTG3_UNLOCALIZED", "HSCHR10_CTG1"]
+    )]
+    #[case(
+        ContigOrder::Lexicographic,
+        vec!["1", "10", "2", "HSCHR10_CTG1", "HSCHR1_CTG1_UNLOCALIZED", "HSCHR1_CTG3_UNLOCALIZED", "MT", "X", "Y"]
+    )]
+    fn test_sort_contigs(#[case] order: ContigOrder, #[case] expected: Vec<&str>) {
+        let names = [
+            "HSCHR10_CTG1",
+            "MT",
+            "X",
+            "10",
+            "HSCHR1_CTG3_UNLOCALIZED",
+            "2",
+            "Y",
+            "1",
+            "HSCHR1_CTG1_UNLOCALIZED",
+        ];
+        let contigs: Vec<Contig<u8>> = names
+            .iter()
+            .map(|name| {
+                Contig::new(
+                    name.to_string(),
+                    SequenceRole::AssembledMolecule,
+                    name.to_string(),
+                    AssignedMoleculeType::Chromosome,
+                    10,
+                    "na".to_string(),
+                    "na".to_string(),
+                    "na".to_string(),
+                )
+                .unwrap()
+            })
+            .collect();
+        let id = GenomeBuildIdentifier::new("GRCh38".to_string(), "p14".to_string());
+        let mut build = GenomeBuild::new(id, contigs);
+        let actual: Vec<_> = build.contigs().iter().map(|c| c.name()).collect();
+        assert_eq!(actual, names);
+        assert_eq!(build.contig("1").unwrap().ordinal(), 7);
+
+        build.sort_contigs(order);
+        let actual: Vec<_> = build.contigs().iter().map(|c| c.name()).collect();
+        assert_eq!(actual, expected);
+        for (i, contig) in build.contigs().iter().enumerate() {
+            assert_eq!(contig.ordinal(), i);
+        }
+        let mut sorted = build.contigs().to_vec();
+        sorted.reverse();
+        sorted.sort_by_key(Contig::ordinal);
+        assert_eq!(sorted, build.contigs());
     }
 
     fn get_few_contigs() -> Vec<Contig<u8>> {
@@ -1,33 +1,364 @@
-use super::{Contig, Contiged, Strand, Stranded};
-use crate::ops::{Located, Unit};
+use super::{Contig, Contiged, Strand, Stranded, Windows};
+use crate::ops::func::{
+    checked_end_with_coordinate_system, checked_start_with_coordinate_system, difference,
+    end_with_coordinate_system, hull, intersection, join_at_origin, overlap_length,
+    start_with_coordinate_system,
+};
+use crate::ops::{
+    zero_based_on_strand, zero_based_segments_on_strand, GenomicSetOperations, Located, Spanning,
+    Unit,
+};
+use crate::{CoordinateSystem, CoordinateSystemMarker, OneBased, SvartError, ZeroBased};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct GenomicRegion<'g, C> {
+/// A region located on a strand of a contig.
+///
+/// The regions are ordered by their contig, then by their start and end on the forward strand
+/// and by the strand, consistently with the equality. Use [`super::sort_regions`] to order
+/// the regions as the contigs of a genome build.
+///
+/// A region on a circular contig may wrap around the contig origin, in which case its start
+/// is after its end. The set operations and the windows support only the regions that do not
+/// wrap, see [`GenomicRegion::split_at_origin`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenomicRegion<'g, C, S> {
     contig: &'g Contig<C>,
     start: C,
     end: C,
     strand: Strand,
+    system: PhantomData<S>,
 }
 
-impl<'g, C> GenomicRegion<'g, C>
+impl<'g, C, S> GenomicRegion<'g, C, S>
 where
     C: Unit,
+    S: CoordinateSystemMarker,
 {
+    /// Create a region, or `None` if the region is out of the bounds of the `contig`.
+    ///
+    /// The `start` may be after the `end` only if the contig is circular.
     pub fn new(contig: &'g Contig<C>, start: C, end: C, strand: Strand) -> Option<Self> {
-        if start > end || contig.end() < &end {
-            None
-        } else {
-            Some(GenomicRegion {
+        check_coordinates::<C, S>(contig, start, end).ok()?;
+        Some(GenomicRegion {
+            contig,
+            start,
+            end,
+            strand,
+            system: PhantomData,
+        })
+    }
+
+    /// Create a region like [`GenomicRegion::new`], reporting the offending coordinates and
+    /// the contig if the region starts after it ends or is out of the bounds of the contig.
+    pub fn try_new(
+        contig: &'g Contig<C>,
+        start: C,
+        end: C,
+        strand: Strand,
+    ) -> Result<Self, SvartError>
+    where
+        C: Display,
+    {
+        match check_coordinates::<C, S>(contig, start, end) {
+            Ok(()) => Ok(GenomicRegion {
                 contig,
                 start,
                 end,
                 strand,
-            })
+                system: PhantomData,
+            }),
+            Err(CoordinatesError::Inverted) => Err(SvartError::InvalidRegionError {
+                start: start.to_string(),
+                end: end.to_string(),
+            }),
+            Err(CoordinatesError::OutOfBounds) => Err(SvartError::RegionOutOfBoundsError {
+                contig: contig.name().to_string(),
+                length: contig.span().to_string(),
+                start: start.to_string(),
+                end: end.to_string(),
+            }),
+        }
+    }
+
+    /// Create a region from valid zero-based coordinates.
+    pub(crate) fn from_zero_based(contig: &'g Contig<C>, start: C, end: C, strand: Strand) -> Self {
+        let cs = CoordinateSystem::zero_based();
+        GenomicRegion {
+            contig,
+            start: start_with_coordinate_system(start, cs, S::COORDINATE_SYSTEM),
+            end: end_with_coordinate_system(end, cs, S::COORDINATE_SYSTEM),
+            strand,
+            system: PhantomData,
+        }
+    }
+
+    /// Convert the region into the coordinate system `T`.
+    pub fn into_coordinate_system<T>(self) -> GenomicRegion<'g, C, T>
+    where
+        T: CoordinateSystemMarker,
+    {
+        GenomicRegion {
+            contig: self.contig,
+            start: self.start_with_coordinate_system(T::COORDINATE_SYSTEM),
+            end: self.end_with_coordinate_system(T::COORDINATE_SYSTEM),
+            strand: self.strand,
+            system: PhantomData,
         }
     }
+
+    pub fn into_zero_based(self) -> GenomicRegion<'g, C, ZeroBased> {
+        self.into_coordinate_system()
+    }
+
+    pub fn into_one_based(self) -> GenomicRegion<'g, C, OneBased> {
+        self.into_coordinate_system()
+    }
+
+    /// Get the contig, borrowed for the lifetime of the genome build rather than the region.
+    pub(crate) fn contig_ref(&self) -> &'g Contig<C> {
+        self.contig
+    }
+
+    /// Check if the region wraps around the origin of its circular contig.
+    pub fn is_wrapping(&self) -> bool {
+        let cs = CoordinateSystem::zero_based();
+        self.start_with_coordinate_system(cs) > self.end_with_coordinate_system(cs)
+    }
+
+    /// Split a region wrapping around the contig origin into the part before the origin and
+    /// the part after it. A region that does not wrap is returned as is.
+    pub fn split_at_origin(&self) -> Vec<Self> {
+        let (start, end, length) = self.zero_based_with_length();
+        if start <= end {
+            return vec![self.clone()];
+        }
+        [(start, length), (C::zero(), end)]
+            .into_iter()
+            .filter(|(start, end)| start < end)
+            .map(|(s, e)| GenomicRegion::from_zero_based(self.contig, s, e, self.strand))
+            .collect()
+    }
+
+    /// Get the zero-based coordinates of the region and the length of its contig.
+    fn zero_based_with_length(&self) -> (C, C, C) {
+        let cs = CoordinateSystem::zero_based();
+        (
+            self.start_with_coordinate_system(cs),
+            self.end_with_coordinate_system(cs),
+            self.contig.span(),
+        )
+    }
+
+    /// Get the zero-based start and the length of a region on a circular contig, and the
+    /// contig length.
+    fn circular_span(&self) -> (C, C, C) {
+        let (start, end, length) = self.zero_based_with_length();
+        match start > end {
+            true => (start, length - start + end, length),
+            false => (start, end - start, length),
+        }
+    }
+
+    /// Create a region of `span` bases from the zero-based `start` on a circular contig,
+    /// wrapping it around the origin.
+    ///
+    /// A region spanning the whole contig or more is reported as the whole contig.
+    fn with_span(&self, start: C, span: C) -> Self {
+        let length = self.contig.span();
+        let start = if start >= length { C::zero() } else { start };
+        let (start, end) = if span >= length {
+            (C::zero(), length)
+        } else if start > length - span {
+            (start, span - (length - start))
+        } else {
+            (start, start + span)
+        };
+        GenomicRegion::from_zero_based(self.contig, start, end, self.strand)
+    }
+
+    /// Extend the region by `upstream` bases at the start and `downstream` bases at the end,
+    /// clamping the region to the bounds of the contig.
+    ///
+    /// On a circular contig, the region is extended around the origin instead.
+    pub fn extend(&self, upstream: u64, downstream: u64) -> Self {
+        if self.contig.is_circular() {
+            let (start, span, length) = self.circular_span();
+            let upstream = clamp_offset(upstream, length - span);
+            let downstream = clamp_offset(downstream, length - span - upstream);
+            let start = rotate_upstream(start, upstream, length);
+            return self.with_span(start, span + upstream + downstream);
+        }
+        let (start, end, length) = self.zero_based_with_length();
+        let start = start - clamp_offset(upstream, start);
+        let end = end + clamp_offset(downstream, length - end);
+        GenomicRegion::from_zero_based(self.contig, start, end, self.strand)
+    }
+
+    /// Shrink the region by `upstream` bases at the start and `downstream` bases at the end.
+    ///
+    /// The region collapses into an empty region if it is shrunk by more than its length.
+    pub fn shrink(&self, upstream: u64, downstream: u64) -> Self {
+        if self.contig.is_circular() {
+            let (start, span, length) = self.circular_span();
+            let upstream = clamp_offset(upstream, span);
+            let downstream = clamp_offset(downstream, span - upstream);
+            let start = rotate_downstream(start, upstream, length);
+            return self.with_span(start, span - upstream - downstream);
+        }
+        let (start, end, _) = self.zero_based_with_length();
+        let start = start + clamp_offset(upstream, end - start);
+        let end = end - clamp_offset(downstream, end - start);
+        GenomicRegion::from_zero_based(self.contig, start, end, self.strand)
+    }
+
+    /// Move the region by `offset` bases upstream on its strand, stopping at the contig start.
+    ///
+    /// On a circular contig, the region moves around the origin instead.
+    pub fn shift_upstream(&self, offset: u64) -> Self {
+        if self.contig.is_circular() {
+            let (start, span, length) = self.circular_span();
+            let offset = offset_modulo(offset, length);
+            return self.with_span(rotate_upstream(start, offset, length), span);
+        }
+        let (start, end, _) = self.zero_based_with_length();
+        let offset = clamp_offset(offset, start);
+        GenomicRegion::from_zero_based(self.contig, start - offset, end - offset, self.strand)
+    }
+
+    /// Move the region by `offset` bases downstream on its strand, stopping at the contig end.
+    ///
+    /// On a circular contig, the region moves around the origin instead.
+    pub fn shift_downstream(&self, offset: u64) -> Self {
+        if self.contig.is_circular() {
+            let (start, span, length) = self.circular_span();
+            let offset = offset_modulo(offset, length);
+            return self.with_span(rotate_downstream(start, offset, length), span);
+        }
+        let (start, end, length) = self.zero_based_with_length();
+        let offset = clamp_offset(offset, length - end);
+        GenomicRegion::from_zero_based(self.contig, start + offset, end + offset, self.strand)
+    }
+
+    /// Get the region of up to `length` bases immediately upstream of this region on its strand.
+    ///
+    /// On a circular contig, the flank may wrap around the origin but never overlaps the region.
+    pub fn upstream_flank(&self, length: u64) -> Self {
+        if self.contig.is_circular() {
+            let (start, span, contig_length) = self.circular_span();
+            let length = clamp_offset(length, contig_length - span);
+            return self.with_span(rotate_upstream(start, length, contig_length), length);
+        }
+        let (start, _, _) = self.zero_based_with_length();
+        let flank_start = start - clamp_offset(length, start);
+        GenomicRegion::from_zero_based(self.contig, flank_start, start, self.strand)
+    }
+
+    /// Get the region of up to `length` bases immediately downstream of this region on its strand.
+    ///
+    /// On a circular contig, the flank may wrap around the origin but never overlaps the region.
+    pub fn downstream_flank(&self, length: u64) -> Self {
+        if self.contig.is_circular() {
+            let (start, span, contig_length) = self.circular_span();
+            let length = clamp_offset(length, contig_length - span);
+            return self.with_span(rotate_downstream(start, span, contig_length), length);
+        }
+        let (_, end, contig_length) = self.zero_based_with_length();
+        let flank_end = end + clamp_offset(length, contig_length - end);
+        GenomicRegion::from_zero_based(self.contig, end, flank_end, self.strand)
+    }
+
+    /// Tile the region with windows on its strand, starting from the upstream end.
+    /// See [`Windows`] for details.
+    pub fn windows(&self, size: C, step: C, keep_trailing: bool) -> Windows<'g, C, S> {
+        let (start, end, _) = self.zero_based_with_length();
+        Windows::new(
+            self.contig,
+            self.strand,
+            start,
+            end,
+            size,
+            step,
+            keep_trailing,
+        )
+    }
+}
+
+/// The reason why coordinates do not form a region on a contig.
+enum CoordinatesError {
+    Inverted,
+    OutOfBounds,
 }
 
-impl<'g, C> Contiged<C> for GenomicRegion<'g, C>
+/// Check that the coordinates in the coordinate system `S` form a region on the `contig`.
+fn check_coordinates<C, S>(contig: &Contig<C>, start: C, end: C) -> Result<(), CoordinatesError>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    let cs = CoordinateSystem::zero_based();
+    let start = checked_start_with_coordinate_system(start, S::COORDINATE_SYSTEM, cs)
+        .ok_or(CoordinatesError::OutOfBounds)?;
+    let end = checked_end_with_coordinate_system(end, S::COORDINATE_SYSTEM, cs)
+        .ok_or(CoordinatesError::OutOfBounds)?;
+    let length = contig.span();
+    if start < C::zero() || end < C::zero() || length < start || length < end {
+        Err(CoordinatesError::OutOfBounds)
+    } else if start > end && !contig.is_circular() {
+        Err(CoordinatesError::Inverted)
+    } else {
+        Ok(())
+    }
+}
+
+/// Convert an offset into the unit, capping it at `max`.
+fn clamp_offset<C: Unit>(offset: u64, max: C) -> C {
+    C::from_u64(offset).map_or(max, |offset| offset.min(max))
+}
+
+/// Reduce an offset on a circular contig of `length` to less than a full turn.
+fn offset_modulo<C: Unit>(offset: u64, length: C) -> C {
+    let offset = match length.to_u64() {
+        Some(length) => offset.checked_rem(length).unwrap_or(0),
+        None => offset,
+    };
+    clamp_offset(offset, length)
+}
+
+/// Move the zero-based `position` on a circular contig of `length` by up to `length` bases
+/// towards the contig start, wrapping around the origin.
+fn rotate_upstream<C: Unit>(position: C, offset: C, length: C) -> C {
+    match position >= offset {
+        true => position - offset,
+        false => position + (length - offset),
+    }
+}
+
+/// Move the zero-based `position` on a circular contig of `length` by up to `length` bases
+/// towards the contig end, wrapping around the origin.
+fn rotate_downstream<C: Unit>(position: C, offset: C, length: C) -> C {
+    match offset < length - position {
+        true => position + offset,
+        false => offset - (length - position),
+    }
+}
+
+impl<'g, C, S> GenomicRegion<'g, C, S> {
+    /// Create a region from coordinates already checked to be valid in the coordinate system `S`.
+    pub(crate) fn new_unchecked(contig: &'g Contig<C>, start: C, end: C, strand: Strand) -> Self {
+        GenomicRegion {
+            contig,
+            start,
+            end,
+            strand,
+            system: PhantomData,
+        }
+    }
+}
+
+impl<'g, C, S> Contiged<C> for GenomicRegion<'g, C, S>
 where
     C: Unit,
 {
@@ -37,7 +368,13 @@ where
     }
 }
 
-impl<'g, C> Located<C> for GenomicRegion<'g, C> {
+impl<'g, C, S> Located<C> for GenomicRegion<'g, C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    type System = S;
+
     fn start(&self) -> &C {
         &self.start
     }
@@ -45,40 +382,712 @@ impl<'g, C> Located<C> for GenomicRegion<'g, C> {
     fn end(&self) -> &C {
         &self.end
     }
+
+    fn circular_length(&self) -> Option<C> {
+        self.contig.is_circular().then(|| self.contig.span())
+    }
+}
+
+impl<'g, C, S> PartialOrd for GenomicRegion<'g, C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'g, C, S> Ord for GenomicRegion<'g, C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.contig
+            .cmp(other.contig)
+            .then_with(|| {
+                zero_based_on_strand(self, Strand::Forward)
+                    .cmp(&zero_based_on_strand(other, Strand::Forward))
+            })
+            .then_with(|| self.strand.cmp(&other.strand))
+    }
+}
+
+impl<'g, C, S> Hash for GenomicRegion<'g, C, S>
+where
+    C: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.contig.hash(state);
+        self.start.hash(state);
+        self.end.hash(state);
+        self.strand.hash(state);
+    }
 }
 
-impl<'g, C> Stranded for GenomicRegion<'g, C> {
+impl<'g, C, S> Display for GenomicRegion<'g, C, S>
+where
+    C: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}-{}:{}",
+            self.contig.name(),
+            self.start,
+            self.end,
+            self.strand
+        )
+    }
+}
+
+impl<'g, C, S> Stranded for GenomicRegion<'g, C, S> {
     fn strand(&self) -> Strand {
         self.strand
     }
 }
 
+impl<'g, C, S> GenomicRegion<'g, C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    /// Get the zero-based segments of the `region` on the strand of `self`,
+    /// split at the origin of a circular contig.
+    fn segments_of(&self, region: &Self) -> Vec<(C, C)> {
+        zero_based_segments_on_strand(region, self.strand)
+    }
+
+    /// Join the zero-based `pieces` on the strand of `self` into regions.
+    fn join_pieces(&self, pieces: Vec<(C, C)>) -> Vec<Self> {
+        join_at_origin(pieces, self.circular_length())
+            .into_iter()
+            .map(|(s, e)| GenomicRegion::from_zero_based(self.contig, s, e, self.strand))
+            .collect()
+    }
+}
+
+/// The regions wrapping around the origin of a circular contig are split at the origin
+/// and the results touching the origin are joined into a wrapping region again.
+impl<'g, C, S> GenomicSetOperations<C> for GenomicRegion<'g, C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    type Output = GenomicRegion<'g, C, S>;
+
+    fn overlap_length(&self, other: &Self) -> C {
+        if self.contig.ne(other.contig) {
+            return C::zero();
+        }
+        let segments = self.segments_of(self);
+        let other_segments = self.segments_of(other);
+        segments
+            .iter()
+            .flat_map(|(a_start, a_end)| {
+                other_segments
+                    .iter()
+                    .map(move |(b_start, b_end)| overlap_length(a_start, a_end, b_start, b_end))
+            })
+            .fold(C::zero(), |total, length| total + length)
+    }
+
+    fn intersection(&self, other: &Self) -> Option<Self::Output> {
+        if self.contig.ne(other.contig) {
+            return None;
+        }
+        let segments = self.segments_of(self);
+        let other_segments = self.segments_of(other);
+        let pieces = segments
+            .iter()
+            .flat_map(|(a_start, a_end)| {
+                other_segments.iter().filter_map(move |(b_start, b_end)| {
+                    intersection(a_start, a_end, b_start, b_end)
+                })
+            })
+            .collect();
+        single(self.join_pieces(pieces))
+    }
+
+    fn union(&self, other: &Self) -> Option<Self::Output> {
+        if self.contig.ne(other.contig) {
+            return None;
+        }
+        let mut segments = self.segments_of(self);
+        let other_segments = self.segments_of(other);
+        segments.extend(other_segments);
+        single(self.join_pieces(segments))
+    }
+
+    fn hull(&self, other: &Self) -> Option<Self::Output> {
+        if self.contig.ne(other.contig) {
+            return None;
+        }
+        let mut segments = self.segments_of(self);
+        let other_segments = self.segments_of(other);
+        if !self.is_wrapping() && !other.is_wrapping() {
+            let (a_start, a_end) = segments[0];
+            let (b_start, b_end) = other_segments[0];
+            let (start, end) = hull(&a_start, &a_end, &b_start, &b_end);
+            return Some(GenomicRegion::from_zero_based(
+                self.contig,
+                start,
+                end,
+                self.strand,
+            ));
+        }
+        // The hull of a wrapping region is the complement of the largest gap between the regions.
+        segments.extend(other_segments);
+        let length = self.contig.span();
+        let pieces = join_at_origin(segments, None);
+        let (first_start, _) = pieces[0];
+        let (_, last_end) = pieces[pieces.len() - 1];
+        let mut hull = (first_start, last_end);
+        let mut largest_gap = first_start + (length - last_end);
+        for window in pieces.windows(2) {
+            let ((_, gap_start), (gap_end, _)) = (window[0], window[1]);
+            if gap_end - gap_start > largest_gap {
+                largest_gap = gap_end - gap_start;
+                hull = (gap_end, gap_start);
+            }
+        }
+        Some(GenomicRegion::from_zero_based(
+            self.contig,
+            hull.0,
+            hull.1,
+            self.strand,
+        ))
+    }
+
+    fn difference(&self, other: &Self) -> Vec<Self::Output> {
+        if self.contig.ne(other.contig) {
+            return vec![self.clone()];
+        }
+        let mut pieces = self.segments_of(self);
+        let other_segments = self.segments_of(other);
+        for (b_start, b_end) in other_segments {
+            pieces = pieces
+                .iter()
+                .flat_map(|(a_start, a_end)| difference(a_start, a_end, &b_start, &b_end))
+                .collect();
+        }
+        self.join_pieces(pieces)
+    }
+}
+
+/// Get the only region of `regions`, or `None` if there are none or several regions.
+fn single<T>(mut regions: Vec<T>) -> Option<T> {
+    match regions.len() {
+        1 => regions.pop(),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Contig, GenomicRegion, Strand};
     use crate::genomic::{AssignedMoleculeType, SequenceRole};
-    use crate::ops::{GenomicallyContains, GenomicallyOverlaps};
+    use crate::genomic::{StrandMode, Stranded};
+    use crate::ops::{
+        GenomicSetOperations, GenomicallyContains, GenomicallyOverlaps, Located, Spanning,
+        Transposable,
+    };
+    use crate::{OneBased, ZeroBased};
     use rstest::rstest;
 
-    #[rstest]
-    fn test_genomic_region() {
-        let length: u32 = 10000;
-        let contig: Contig<u32> = Contig::new(
+    fn get_contig() -> Contig<u32> {
+        Contig::new(
             "chr1".to_string(),
             SequenceRole::AssembledMolecule,
             "something".to_string(),
             AssignedMoleculeType::Chromosome,
-            length,
+            10000,
             "CM061752.1".to_string(),
             "NM_1234".to_string(),
             "chr6".to_string(),
         )
-        .unwrap();
-        let genomic_region: GenomicRegion<u32> =
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_genomic_region() {
+        let contig = get_contig();
+        let genomic_region: GenomicRegion<u32, ZeroBased> =
             GenomicRegion::new(&contig, 10, 20, Strand::Forward).unwrap();
-        let genomic_region_1: GenomicRegion<u32> =
+        let genomic_region_1: GenomicRegion<u32, ZeroBased> =
             GenomicRegion::new(&contig, 15, 20, Strand::Forward).unwrap();
-        assert_eq!(genomic_region.contains(&genomic_region_1), true);
-        assert_eq!(genomic_region.overlaps(&genomic_region_1), true);
+        assert!(genomic_region.contains(&genomic_region_1));
+        assert!(genomic_region.overlaps(&genomic_region_1));
+    }
+
+    #[rstest]
+    #[case(1, 10000, true)]
+    #[case(1, 10001, false)]
+    #[case(11, 10, true)]
+    #[case(12, 10, false)]
+    fn test_new_one_based(#[case] start: u32, #[case] end: u32, #[case] expected: bool) {
+        let contig = get_contig();
+        let region: Option<GenomicRegion<u32, OneBased>> =
+            GenomicRegion::new(&contig, start, end, Strand::Forward);
+        assert_eq!(region.is_some(), expected);
+    }
+
+    #[rstest]
+    #[case(0, 10000, true)]
+    #[case(0, 10001, false)]
+    #[case(11, 10, false)]
+    fn test_new_zero_based(#[case] start: u32, #[case] end: u32, #[case] expected: bool) {
+        let contig = get_contig();
+        let region: Option<GenomicRegion<u32, ZeroBased>> =
+            GenomicRegion::new(&contig, start, end, Strand::Forward);
+        assert_eq!(region.is_some(), expected);
+    }
+
+    #[rstest]
+    #[case(10, 5, "Invalid region error: start 10 is after end 5")]
+    #[case(
+        9_990,
+        10_005,
+        "Out of bounds error: Region 9990-10005 is out of the bounds of contig 'chr1' of length 10000"
+    )]
+    #[case(
+        10_001,
+        10_001,
+        "Out of bounds error: Region 10001-10001 is out of the bounds of contig 'chr1' of length 10000"
+    )]
+    fn test_try_new_one_based(#[case] start: u32, #[case] end: u32, #[case] expected: &str) {
+        let contig = get_contig();
+        let error = GenomicRegion::<u32, OneBased>::try_new(&contig, start, end, Strand::Forward)
+            .unwrap_err();
+        assert_eq!(error.to_string(), expected);
+        assert!(GenomicRegion::<u32, OneBased>::try_new(&contig, 1, 5, Strand::Forward).is_ok());
+    }
+
+    #[rstest]
+    fn test_new_one_based_start_below_lower_bound() {
+        let contig = get_contig();
+        assert!(GenomicRegion::<u32, OneBased>::new(&contig, 0, 10, Strand::Forward).is_none());
+        let error =
+            GenomicRegion::<u32, OneBased>::try_new(&contig, 0, 10, Strand::Forward).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Out of bounds error: Region 0-10 is out of the bounds of contig 'chr1' of length 10000"
+        );
+    }
+
+    #[rstest]
+    fn test_new_signed() {
+        let contig: Contig<i64> = Contig::new(
+            "chr1".to_string(),
+            SequenceRole::AssembledMolecule,
+            "1".to_string(),
+            AssignedMoleculeType::Chromosome,
+            10000,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap();
+        let region: Option<GenomicRegion<i64, ZeroBased>> =
+            GenomicRegion::new(&contig, -10, 20, Strand::Forward);
+        assert!(region.is_none());
+        let region: Option<GenomicRegion<i64, ZeroBased>> =
+            GenomicRegion::new(&contig, 0, 20, Strand::Forward);
+        assert!(region.is_some());
+    }
+
+    #[rstest]
+    #[case(Strand::Unknown, 10, 20, StrandMode::Ignore, true, true)]
+    #[case(Strand::Unknown, 10, 20, StrandMode::Strict, true, true)]
+    #[case(Strand::Forward, 10, 20, StrandMode::Ignore, true, true)]
+    #[case(Strand::Forward, 10, 20, StrandMode::Strict, false, false)]
+    #[case(Strand::Reverse, 9980, 9990, StrandMode::Ignore, true, true)]
+    #[case(Strand::Reverse, 9980, 9990, StrandMode::Strict, false, false)]
+    #[case(Strand::Reverse, 10, 20, StrandMode::Ignore, false, false)]
+    fn test_unknown_strand(
+        #[case] strand: Strand,
+        #[case] start: u32,
+        #[case] end: u32,
+        #[case] mode: StrandMode,
+        #[case] overlaps: bool,
+        #[case] contains: bool,
+    ) {
+        let contig = get_contig();
+        let unknown: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 5, 25, Strand::Unknown).unwrap();
+        let other = GenomicRegion::new(&contig, start, end, strand).unwrap();
+        assert_eq!(unknown.overlaps_with_mode(&other, mode), overlaps);
+        assert_eq!(unknown.contains_with_mode(&other, mode), contains);
+        assert_eq!(unknown.start_on_strand(Strand::Forward), 5);
+        assert_eq!(unknown.start_on_strand(Strand::Reverse), 9975);
+    }
+
+    #[rstest]
+    fn test_into_coordinate_system() {
+        let contig = get_contig();
+        let zero_based: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 10, 20, Strand::Forward).unwrap();
+        let one_based = zero_based.clone().into_one_based();
+        assert_eq!(one_based.coordinates(), (&11, &20));
+
+        // [9981, 9990] on the reverse strand is [11, 20] on the forward strand.
+        let reverse: GenomicRegion<u32, OneBased> =
+            GenomicRegion::new(&contig, 9981, 9990, Strand::Reverse).unwrap();
+        assert!(reverse.contains(&one_based));
+        assert!(zero_based.contains(&reverse.into_zero_based()));
+    }
+
+    #[rstest]
+    fn test_set_operations() {
+        let contig = get_contig();
+        let forward: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 10, 30, Strand::Forward).unwrap();
+        // (9975, 9985] on the reverse strand is (15, 25] on the forward strand.
+        let reverse: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 9975, 9985, Strand::Reverse).unwrap();
+
+        assert_eq!(forward.overlap_length(&reverse), 10);
+        assert_eq!(
+            forward.intersection(&reverse),
+            GenomicRegion::new(&contig, 15, 25, Strand::Forward)
+        );
+        assert_eq!(
+            reverse.intersection(&forward),
+            GenomicRegion::new(&contig, 9975, 9985, Strand::Reverse)
+        );
+        assert_eq!(
+            reverse.hull(&forward),
+            GenomicRegion::new(&contig, 9970, 9990, Strand::Reverse)
+        );
+        assert_eq!(
+            forward.difference(&reverse),
+            vec![
+                GenomicRegion::new(&contig, 10, 15, Strand::Forward).unwrap(),
+                GenomicRegion::new(&contig, 25, 30, Strand::Forward).unwrap(),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_set_operations_different_contigs() {
+        let contig = get_contig();
+        let other_contig = Contig::new(
+            "chr2".to_string(),
+            SequenceRole::AssembledMolecule,
+            "2".to_string(),
+            AssignedMoleculeType::Chromosome,
+            10000,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap();
+        let a: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 10, 30, Strand::Forward).unwrap();
+        let b: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&other_contig, 10, 30, Strand::Forward).unwrap();
+        assert_eq!(a.overlap_length(&b), 0);
+        assert_eq!(a.intersection(&b), None);
+        assert_eq!(a.union(&b), None);
+        assert_eq!(a.hull(&b), None);
+        assert_eq!(a.difference(&b), vec![a.clone()]);
+    }
+
+    #[rstest]
+    #[case(100, 200, 10, 20, 90, 220)]
+    #[case(5, 200, 10, 20, 0, 220)]
+    #[case(100, 9990, 10, 20, 90, 10000)]
+    fn test_extend(
+        #[case] start: u32,
+        #[case] end: u32,
+        #[case] upstream: u64,
+        #[case] downstream: u64,
+        #[case] expected_start: u32,
+        #[case] expected_end: u32,
+    ) {
+        let contig = get_contig();
+        let region: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, start, end, Strand::Forward).unwrap();
+        let extended = region.extend(upstream, downstream);
+        assert_eq!(extended.coordinates(), (&expected_start, &expected_end));
+    }
+
+    #[rstest]
+    #[case(100, 200, 10, 20, 110, 180)]
+    #[case(100, 200, 60, 60, 160, 160)]
+    #[case(100, 200, 150, 0, 200, 200)]
+    fn test_shrink(
+        #[case] start: u32,
+        #[case] end: u32,
+        #[case] upstream: u64,
+        #[case] downstream: u64,
+        #[case] expected_start: u32,
+        #[case] expected_end: u32,
+    ) {
+        let contig = get_contig();
+        let region: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, start, end, Strand::Forward).unwrap();
+        let shrunk = region.shrink(upstream, downstream);
+        assert_eq!(shrunk.coordinates(), (&expected_start, &expected_end));
+    }
+
+    #[rstest]
+    fn test_shift() {
+        let contig = get_contig();
+        let region: GenomicRegion<u32, OneBased> =
+            GenomicRegion::new(&contig, 101, 200, Strand::Forward).unwrap();
+        assert_eq!(region.shift_upstream(50).coordinates(), (&51, &150));
+        assert_eq!(region.shift_upstream(500).coordinates(), (&1, &100));
+        assert_eq!(region.shift_downstream(50).coordinates(), (&151, &250));
+        assert_eq!(
+            region.shift_downstream(50_000).coordinates(),
+            (&9901, &10000)
+        );
+    }
+
+    #[rstest]
+    fn test_flanks() {
+        let contig = get_contig();
+        let forward: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 100, 200, Strand::Forward).unwrap();
+        assert_eq!(forward.upstream_flank(50).coordinates(), (&50, &100));
+        assert_eq!(forward.upstream_flank(500).coordinates(), (&0, &100));
+        assert_eq!(forward.downstream_flank(50).coordinates(), (&200, &250));
+
+        // (9800, 9900] on the reverse strand is (100, 200] on the forward strand.
+        let reverse: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 9800, 9900, Strand::Reverse).unwrap();
+        let upstream = reverse.upstream_flank(50);
+        assert_eq!(upstream.strand(), Strand::Reverse);
+        assert_eq!(upstream.start_on_strand(Strand::Forward), 200);
+        assert_eq!(upstream.end_on_strand(Strand::Forward), 250);
+        let downstream = reverse.downstream_flank(500);
+        assert_eq!(downstream.start_on_strand(Strand::Forward), 0);
+        assert_eq!(downstream.end_on_strand(Strand::Forward), 100);
+    }
+
+    #[rstest]
+    fn test_offsets_are_clamped_on_signed_contig() {
+        let contig: Contig<i32> = Contig::new(
+            "1".to_string(),
+            SequenceRole::AssembledMolecule,
+            "1".to_string(),
+            AssignedMoleculeType::Chromosome,
+            100,
+            "na".to_string(),
+            "na".to_string(),
+            "na".to_string(),
+        )
+        .unwrap();
+        let region: GenomicRegion<i32, ZeroBased> =
+            GenomicRegion::new(&contig, 90, 95, Strand::Forward).unwrap();
+        assert_eq!(region.shift_upstream(20).coordinates(), (&70, &75));
+        assert_eq!(region.shift_downstream(20).coordinates(), (&95, &100));
+        assert_eq!(region.extend(5, 5).coordinates(), (&85, &100));
+        assert_eq!(region.upstream_flank(10).coordinates(), (&80, &90));
+
+        let max = u64::MAX;
+        assert_eq!(region.shift_upstream(max).coordinates(), (&0, &5));
+        assert_eq!(region.shift_downstream(max).coordinates(), (&95, &100));
+        assert_eq!(region.extend(max, max).coordinates(), (&0, &100));
+        assert_eq!(region.shrink(max, max).coordinates(), (&95, &95));
+        assert_eq!(region.upstream_flank(max).coordinates(), (&0, &90));
+        assert_eq!(region.downstream_flank(max).coordinates(), (&95, &100));
+    }
+
+    fn get_circular_contig() -> Contig<u32> {
+        Contig::new(
+            "MT".to_string(),
+            SequenceRole::AssembledMolecule,
+            "MT".to_string(),
+            AssignedMoleculeType::Mitochondrion,
+            100,
+            "J01415.2".to_string(),
+            "NC_012920.1".to_string(),
+            "chrM".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(90, 10, true)]
+    #[case(100, 10, true)]
+    #[case(101, 10, false)]
+    #[case(90, 101, false)]
+    fn test_new_circular(#[case] start: u32, #[case] end: u32, #[case] expected: bool) {
+        let contig = get_circular_contig();
+        let region: Option<GenomicRegion<u32, ZeroBased>> =
+            GenomicRegion::new(&contig, start, end, Strand::Forward);
+        assert_eq!(region.is_some(), expected);
+        let linear = get_contig();
+        assert!(GenomicRegion::<u32, ZeroBased>::new(&linear, 90, 10, Strand::Forward).is_none());
+    }
+
+    #[rstest]
+    fn test_wrapping() {
+        let contig = get_circular_contig();
+        // (90, 100] and (0, 10] on the forward strand.
+        let region: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 90, 10, Strand::Forward).unwrap();
+        assert!(region.is_wrapping());
+        assert_eq!(region.span(), 20);
+        assert!(!region.is_empty());
+        assert_eq!(region.to_string(), "MT:90-10:+");
+        assert_eq!(region.start_on_strand(Strand::Reverse), 90);
+        assert_eq!(region.end_on_strand(Strand::Reverse), 10);
+        assert_eq!(
+            region
+                .split_at_origin()
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>(),
+            vec!["MT:90-100:+", "MT:0-10:+"]
+        );
+
+        let before = GenomicRegion::new(&contig, 95, 100, Strand::Forward).unwrap();
+        // (95, 100] on the forward strand.
+        let reverse = GenomicRegion::new(&contig, 0, 5, Strand::Reverse).unwrap();
+        let after = GenomicRegion::new(&contig, 5, 15, Strand::Forward).unwrap();
+        let middle = GenomicRegion::new(&contig, 20, 80, Strand::Forward).unwrap();
+        let around = GenomicRegion::new(&contig, 85, 15, Strand::Forward).unwrap();
+        assert!(GenomicallyOverlaps::overlaps(&region, &before));
+        assert!(GenomicallyOverlaps::overlaps(&region, &reverse));
+        assert!(GenomicallyOverlaps::overlaps(&region, &after));
+        assert!(!GenomicallyOverlaps::overlaps(&region, &middle));
+        assert!(GenomicallyContains::contains(&region, &before));
+        assert!(GenomicallyContains::contains(&region, &reverse));
+        assert!(!GenomicallyContains::contains(&region, &after));
+        assert!(GenomicallyContains::contains(&around, &region));
+        assert!(!GenomicallyContains::contains(&region, &around));
+        assert!(!region.split_at_origin()[0].is_wrapping());
+    }
+
+    #[rstest]
+    fn test_wrapping_set_operations() {
+        let contig = get_circular_contig();
+        let region = |start, end, strand| -> GenomicRegion<u32, ZeroBased> {
+            GenomicRegion::new(&contig, start, end, strand).unwrap()
+        };
+        let to_strings = |regions: Vec<GenomicRegion<u32, ZeroBased>>| -> Vec<String> {
+            regions.iter().map(|r| r.to_string()).collect()
+        };
+        // (90, 100] and (0, 10] on the forward strand.
+        let wrapping = region(90, 10, Strand::Forward);
+
+        let overlapping = region(5, 30, Strand::Forward);
+        assert_eq!(wrapping.overlap_length(&overlapping), 5);
+        assert_eq!(
+            wrapping.intersection(&overlapping),
+            Some(region(5, 10, Strand::Forward))
+        );
+        assert_eq!(
+            wrapping.union(&overlapping),
+            Some(region(90, 30, Strand::Forward))
+        );
+        assert_eq!(
+            wrapping.hull(&overlapping),
+            Some(region(90, 30, Strand::Forward))
+        );
+        assert_eq!(
+            to_strings(wrapping.difference(&overlapping)),
+            vec!["MT:90-5:+"]
+        );
+        assert_eq!(
+            to_strings(overlapping.difference(&wrapping)),
+            vec!["MT:10-30:+"]
+        );
+
+        let apart = region(20, 30, Strand::Forward);
+        assert_eq!(wrapping.overlap_length(&apart), 0);
+        assert_eq!(wrapping.intersection(&apart), None);
+        assert_eq!(wrapping.union(&apart), None);
+        assert_eq!(wrapping.hull(&apart), Some(region(90, 30, Strand::Forward)));
+        assert_eq!(to_strings(wrapping.difference(&apart)), vec!["MT:90-10:+"]);
+
+        let inner = region(95, 5, Strand::Forward);
+        assert_eq!(wrapping.overlap_length(&inner), 10);
+        assert_eq!(wrapping.intersection(&inner), Some(inner.clone()));
+        assert_eq!(wrapping.union(&inner), Some(wrapping.clone()));
+        assert_eq!(wrapping.hull(&inner), Some(wrapping.clone()));
+        assert_eq!(
+            to_strings(wrapping.difference(&inner)),
+            vec!["MT:5-10:+", "MT:90-95:+"]
+        );
+        assert!(inner.difference(&wrapping).is_empty());
+
+        // Both ends of the wrapping region overlap the region.
+        let across = region(5, 95, Strand::Forward);
+        assert_eq!(wrapping.overlap_length(&across), 10);
+        assert_eq!(wrapping.intersection(&across), None);
+        assert_eq!(
+            wrapping.union(&across),
+            Some(region(0, 100, Strand::Forward))
+        );
+
+        // (70, 95] on the reverse strand is (5, 30] on the forward strand.
+        let reverse = region(70, 95, Strand::Reverse);
+        assert_eq!(
+            wrapping.intersection(&reverse),
+            Some(region(5, 10, Strand::Forward))
+        );
+        assert_eq!(
+            reverse.intersection(&wrapping),
+            Some(region(90, 95, Strand::Reverse))
+        );
+
+        // The linear regions touching the origin are joined as well.
+        let start = region(0, 10, Strand::Forward);
+        let end = region(90, 100, Strand::Forward);
+        assert_eq!(start.union(&end), Some(wrapping.clone()));
+    }
+
+    #[rstest]
+    fn test_circular_operations() {
+        let contig = get_circular_contig();
+        let region: GenomicRegion<u32, OneBased> =
+            GenomicRegion::new(&contig, 91, 10, Strand::Forward).unwrap();
+        assert_eq!(region.extend(5, 5).to_string(), "MT:86-15:+");
+        assert_eq!(region.extend(50, 50).to_string(), "MT:1-100:+");
+        assert_eq!(region.shrink(15, 0).to_string(), "MT:6-10:+");
+        assert_eq!(region.shift_downstream(15).to_string(), "MT:6-25:+");
+        assert_eq!(region.shift_upstream(95).to_string(), "MT:96-15:+");
+        assert_eq!(region.upstream_flank(10).to_string(), "MT:81-90:+");
+        assert_eq!(region.downstream_flank(100).to_string(), "MT:11-90:+");
+
+        let linear: GenomicRegion<u32, OneBased> =
+            GenomicRegion::new(&contig, 1, 10, Strand::Forward).unwrap();
+        assert_eq!(linear.upstream_flank(5).to_string(), "MT:96-100:+");
+        assert!(linear.upstream_flank(5).shift_downstream(3).is_wrapping());
+    }
+
+    #[rstest]
+    fn test_circular_operations_on_signed_contig() {
+        let contig: Contig<i32> = Contig::new(
+            "MT".to_string(),
+            SequenceRole::AssembledMolecule,
+            "MT".to_string(),
+            AssignedMoleculeType::Mitochondrion,
+            100,
+            "J01415.2".to_string(),
+            "NC_012920.1".to_string(),
+            "chrM".to_string(),
+        )
+        .unwrap();
+        assert!(GenomicRegion::<i32, ZeroBased>::new(&contig, 10, -5, Strand::Forward).is_none());
+
+        let region: GenomicRegion<i32, ZeroBased> =
+            GenomicRegion::new(&contig, 90, 10, Strand::Forward).unwrap();
+        assert_eq!(region.shift_upstream(5).coordinates(), (&85, &5));
+        assert_eq!(region.shift_downstream(15).coordinates(), (&5, &25));
+
+        let max = u64::MAX;
+        // u64::MAX is 15 modulo the contig length.
+        assert_eq!(region.shift_upstream(max).coordinates(), (&75, &95));
+        assert_eq!(region.shift_downstream(max).coordinates(), (&5, &25));
+        assert_eq!(region.extend(max, max).coordinates(), (&0, &100));
+        assert_eq!(region.shrink(max, max).coordinates(), (&10, &10));
+        assert_eq!(region.upstream_flank(max).coordinates(), (&10, &90));
+        assert_eq!(region.downstream_flank(max).coordinates(), (&10, &90));
     }
 }
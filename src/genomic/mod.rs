@@ -1,19 +1,34 @@
+mod assembly_report;
 mod assigned_molecule_type;
+#[cfg(any(feature = "grch37", feature = "grch38", feature = "t2t"))]
+mod builds;
+mod chrom_alias;
 mod contig;
+mod contig_builder;
 mod contiged;
+mod fai;
 mod genome;
 mod genomic_region;
+mod order;
+mod owned_genomic_region;
 mod sequence_role;
 mod strand;
 mod stranded;
+mod topology;
 mod variant_type;
+mod windows;
 
 pub use assigned_molecule_type::*;
 pub use contig::*;
+pub use contig_builder::*;
 pub use contiged::*;
 pub use genome::*;
 pub use genomic_region::*;
+pub use order::*;
+pub use owned_genomic_region::*;
 pub use sequence_role::*;
 pub use strand::*;
 pub use stranded::*;
+pub use topology::*;
 pub use variant_type::*;
+pub use windows::*;
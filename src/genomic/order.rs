@@ -0,0 +1,149 @@
+use super::{Contig, Contiged, GenomeBuild, Strand, Stranded};
+use crate::ops::{zero_based_on_strand, Located, Unit};
+use std::cmp::Ordering;
+
+/// Compare the regions by the position of their contigs in the `build`, then by their start
+/// and end on the forward strand and by the strand.
+///
+/// The regions on contigs missing from the `build` are ordered after the other regions,
+/// by the contig name.
+pub fn compare_regions<C, T>(build: &GenomeBuild<C>, a: &T, b: &T) -> Ordering
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+{
+    sort_key(build, a).cmp(&sort_key(build, b))
+}
+
+/// Sort the regions in the genome order of the `build`, see [`compare_regions`].
+pub fn sort_regions<C, T>(build: &GenomeBuild<C>, regions: &mut [T])
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+{
+    regions.sort_by(|a, b| compare_regions(build, a, b));
+}
+
+/// Check if the regions are sorted in the genome order of the `build`, see [`compare_regions`].
+pub fn is_sorted_regions<'r, C, T, I>(build: &GenomeBuild<C>, regions: I) -> bool
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>> + 'r,
+    I: IntoIterator<Item = &'r T>,
+{
+    let mut regions = regions.into_iter();
+    let mut previous = match regions.next() {
+        Some(region) => region,
+        None => return true,
+    };
+    for region in regions {
+        if compare_regions(build, previous, region) == Ordering::Greater {
+            return false;
+        }
+        previous = region;
+    }
+    true
+}
+
+fn sort_key<'r, C, T>(build: &GenomeBuild<C>, region: &'r T) -> (usize, &'r str, C, C, Strand)
+where
+    C: Unit + 'r,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+{
+    let contig = region.contig();
+    let position = build.position_of(contig).unwrap_or(usize::MAX);
+    let (start, end) = zero_based_on_strand(region, Strand::Forward);
+    (position, contig.name(), start, end, region.strand())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{compare_regions, is_sorted_regions, sort_regions};
+    use crate::genomic::{
+        AssignedMoleculeType, Contig, ContigOrder, GenomeBuild, GenomeBuildIdentifier,
+        GenomicRegion, SequenceRole, Strand,
+    };
+    use crate::ZeroBased;
+    use rstest::rstest;
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+
+    fn build() -> GenomeBuild<u32> {
+        let contigs = ["X", "10", "2"]
+            .iter()
+            .map(|name| {
+                Contig::new(
+                    name.to_string(),
+                    SequenceRole::AssembledMolecule,
+                    name.to_string(),
+                    AssignedMoleculeType::Chromosome,
+                    100,
+                    "na".to_string(),
+                    "na".to_string(),
+                    format!("chr{}", name),
+                )
+                .unwrap()
+            })
+            .collect();
+        let mut build = GenomeBuild::new(
+            GenomeBuildIdentifier::new("GRCh38".to_string(), "p14".to_string()),
+            contigs,
+        );
+        build.sort_contigs(ContigOrder::Karyotypic);
+        build
+    }
+
+    #[rstest]
+    fn test_sort_regions() {
+        let build = build();
+        let two = build.contig("2").unwrap();
+        let ten = build.contig("10").unwrap();
+        let x = build.contig("X").unwrap();
+        let mut regions: Vec<GenomicRegion<u32, ZeroBased>> = vec![
+            GenomicRegion::new(x, 0, 10, Strand::Forward).unwrap(),
+            GenomicRegion::new(ten, 20, 30, Strand::Forward).unwrap(),
+            // (5, 15] on the forward strand.
+            GenomicRegion::new(ten, 85, 95, Strand::Reverse).unwrap(),
+            GenomicRegion::new(two, 50, 60, Strand::Forward).unwrap(),
+            GenomicRegion::new(ten, 5, 15, Strand::Forward).unwrap(),
+        ];
+        assert!(!is_sorted_regions(&build, &regions));
+
+        sort_regions(&build, &mut regions);
+        assert!(is_sorted_regions(&build, &regions));
+        let actual: Vec<_> = regions.iter().map(|r| r.to_string()).collect();
+        assert_eq!(
+            actual,
+            vec![
+                "2:50-60:+",
+                "10:5-15:+",
+                "10:85-95:-",
+                "10:20-30:+",
+                "X:0-10:+"
+            ]
+        );
+        assert_eq!(
+            compare_regions(&build, &regions[1], &regions[2]),
+            Ordering::Less
+        );
+
+        // The natural order compares the contigs by their names rather than the genome order.
+        let mut natural = regions.clone();
+        natural.sort();
+        assert_eq!(natural[0].to_string(), "10:5-15:+");
+        assert!(!is_sorted_regions(&build, &natural));
+    }
+
+    #[rstest]
+    fn test_hash() {
+        let build = build();
+        let two = build.contig("2").unwrap();
+        let regions: Vec<GenomicRegion<u32, ZeroBased>> = vec![
+            GenomicRegion::new(two, 0, 10, Strand::Forward).unwrap(),
+            GenomicRegion::new(two, 0, 10, Strand::Reverse).unwrap(),
+            GenomicRegion::new(two, 0, 10, Strand::Forward).unwrap(),
+        ];
+        let unique: HashSet<_> = regions.into_iter().collect();
+        assert_eq!(unique.len(), 2);
+    }
+}
@@ -0,0 +1,342 @@
+use super::{Contig, Contiged, GenomicRegion, Strand, Stranded};
+use crate::ops::{Located, Spanning, Unit};
+use crate::{CoordinateSystemMarker, SvartError};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A [`GenomicRegion`] that shares the ownership of its contig instead of borrowing it,
+/// e.g. to be stored in long-lived structs or sent across threads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedGenomicRegion<C, S> {
+    contig: Arc<Contig<C>>,
+    start: C,
+    end: C,
+    strand: Strand,
+    system: PhantomData<S>,
+}
+
+impl<C, S> OwnedGenomicRegion<C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    /// Create a region with the same checks as [`GenomicRegion::new`].
+    pub fn new(contig: Arc<Contig<C>>, start: C, end: C, strand: Strand) -> Option<Self> {
+        GenomicRegion::<C, S>::new(&contig, start, end, strand)?;
+        Some(OwnedGenomicRegion {
+            contig,
+            start,
+            end,
+            strand,
+            system: PhantomData,
+        })
+    }
+
+    /// Create a region with the same checks and errors as [`GenomicRegion::try_new`].
+    pub fn try_new(
+        contig: Arc<Contig<C>>,
+        start: C,
+        end: C,
+        strand: Strand,
+    ) -> Result<Self, SvartError>
+    where
+        C: Display,
+    {
+        GenomicRegion::<C, S>::try_new(&contig, start, end, strand)?;
+        Ok(OwnedGenomicRegion {
+            contig,
+            start,
+            end,
+            strand,
+            system: PhantomData,
+        })
+    }
+
+    /// Create a region with the coordinates of `region`, sharing the `contig`.
+    ///
+    /// Returns `None` if the `contig` differs from the contig of the `region`.
+    pub fn from_genomic_region(
+        contig: Arc<Contig<C>>,
+        region: &GenomicRegion<C, S>,
+    ) -> Option<Self> {
+        if contig.as_ref().ne(region.contig()) {
+            return None;
+        }
+        Some(OwnedGenomicRegion {
+            contig,
+            start: *region.start(),
+            end: *region.end(),
+            strand: region.strand(),
+            system: PhantomData,
+        })
+    }
+
+    /// Borrow the region as a [`GenomicRegion`], e.g. to [`GenomicRegion::extend`] it.
+    pub fn as_genomic_region(&self) -> GenomicRegion<'_, C, S> {
+        self.view()
+    }
+
+    pub fn shared_contig(&self) -> &Arc<Contig<C>> {
+        &self.contig
+    }
+}
+
+impl<C, S> OwnedGenomicRegion<C, S>
+where
+    C: Copy,
+{
+    /// Borrow the region as a [`GenomicRegion`] to share its implementations.
+    fn view(&self) -> GenomicRegion<'_, C, S> {
+        GenomicRegion::new_unchecked(&self.contig, self.start, self.end, self.strand)
+    }
+}
+
+/// Shares a single [`Arc`] of each contig among the [`OwnedGenomicRegion`]s, so that
+/// the regions of a contig do not each hold a copy of the contig.
+///
+/// The contigs are shared only if they agree in all fields, so that the equal contigs of
+/// different builds keep their own [`Contig::ordinal`], [`Contig::topology`] and aliases.
+#[derive(Debug, Clone, Default)]
+pub struct ContigInterner<C> {
+    /// The shared contigs, grouped by the contig equality.
+    contigs: HashMap<Arc<Contig<C>>, Vec<Arc<Contig<C>>>>,
+}
+
+impl<C> ContigInterner<C>
+where
+    C: Unit + Hash,
+{
+    pub fn new() -> Self {
+        ContigInterner {
+            contigs: HashMap::new(),
+        }
+    }
+
+    /// Get the shared copy of the `contig`, cloning the contig the first time it is seen.
+    pub fn intern(&mut self, contig: &Contig<C>) -> Arc<Contig<C>> {
+        if let Some(shared) = self.contigs.get_mut(contig) {
+            if let Some(identical) = shared.iter().find(|c| c.is_identical(contig)) {
+                return identical.clone();
+            }
+            let identical = Arc::new(contig.clone());
+            shared.push(identical.clone());
+            return identical;
+        }
+        let shared = Arc::new(contig.clone());
+        self.contigs.insert(shared.clone(), vec![shared.clone()]);
+        shared
+    }
+
+    /// Convert the `region` into an owned region sharing the interned contig.
+    pub fn to_owned_region<S>(&mut self, region: &GenomicRegion<C, S>) -> OwnedGenomicRegion<C, S>
+    where
+        S: CoordinateSystemMarker,
+    {
+        OwnedGenomicRegion {
+            contig: self.intern(region.contig()),
+            start: *region.start(),
+            end: *region.end(),
+            strand: region.strand(),
+            system: PhantomData,
+        }
+    }
+
+    /// Get the number of the distinct contigs.
+    pub fn len(&self) -> usize {
+        self.contigs.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contigs.is_empty()
+    }
+}
+
+impl<C, S> Contiged<C> for OwnedGenomicRegion<C, S>
+where
+    C: Unit,
+{
+    type Contig = Contig<C>;
+    fn contig(&self) -> &Contig<C> {
+        &self.contig
+    }
+}
+
+impl<C, S> Located<C> for OwnedGenomicRegion<C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    type System = S;
+
+    fn start(&self) -> &C {
+        &self.start
+    }
+
+    fn end(&self) -> &C {
+        &self.end
+    }
+
+    fn circular_length(&self) -> Option<C> {
+        self.contig.is_circular().then(|| self.contig.span())
+    }
+}
+
+impl<C, S> Stranded for OwnedGenomicRegion<C, S> {
+    fn strand(&self) -> Strand {
+        self.strand
+    }
+}
+
+impl<C, S> PartialOrd for OwnedGenomicRegion<C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The regions are ordered like [`GenomicRegion`]s.
+impl<C, S> Ord for OwnedGenomicRegion<C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.view().cmp(&other.view())
+    }
+}
+
+impl<C, S> Hash for OwnedGenomicRegion<C, S>
+where
+    C: Copy + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.view().hash(state);
+    }
+}
+
+impl<C, S> Display for OwnedGenomicRegion<C, S>
+where
+    C: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        self.view().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ContigInterner, OwnedGenomicRegion};
+    use crate::genomic::{
+        AssignedMoleculeType, Contig, GenomicRegion, SequenceRole, Strand, Topology,
+    };
+    use crate::ops::{GenomicallyContains, GenomicallyOverlaps, Located, Transposable};
+    use crate::{OneBased, ZeroBased};
+    use rstest::rstest;
+    use std::sync::Arc;
+    use std::thread;
+
+    fn contig() -> Arc<Contig<u32>> {
+        Arc::new(
+            Contig::new(
+                "1".to_string(),
+                SequenceRole::AssembledMolecule,
+                "1".to_string(),
+                AssignedMoleculeType::Chromosome,
+                100,
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[rstest]
+    fn test_owned_genomic_region() {
+        let contig = contig();
+        let region: OwnedGenomicRegion<u32, ZeroBased> =
+            OwnedGenomicRegion::new(contig.clone(), 10, 20, Strand::Forward).unwrap();
+        // (10, 20] on the forward strand.
+        let other = OwnedGenomicRegion::new(contig.clone(), 80, 90, Strand::Reverse).unwrap();
+        assert!(region.overlaps(&other));
+        assert!(region.contains(&other));
+        assert_eq!(other.start_on_strand(Strand::Forward), 10);
+        assert!(OwnedGenomicRegion::<u32, ZeroBased>::new(
+            contig.clone(),
+            90,
+            110,
+            Strand::Forward
+        )
+        .is_none());
+        assert!(OwnedGenomicRegion::<u32, ZeroBased>::try_new(
+            contig.clone(),
+            20,
+            10,
+            Strand::Forward
+        )
+        .is_err());
+
+        let extended = region.as_genomic_region().extend(5, 5);
+        let extended = OwnedGenomicRegion::from_genomic_region(contig.clone(), &extended).unwrap();
+        assert_eq!(extended.to_string(), "1:5-25:+");
+        assert!(Arc::ptr_eq(extended.shared_contig(), &contig));
+
+        let handle = thread::spawn(move || region.end() - region.start());
+        assert_eq!(handle.join().unwrap(), 10);
+    }
+
+    #[rstest]
+    fn test_overlaps_contig_with_aliases() {
+        let contig = contig();
+        let mut aliased = contig.as_ref().clone();
+        aliased.add_alias("chrM".to_string());
+        let region: OwnedGenomicRegion<u32, ZeroBased> =
+            OwnedGenomicRegion::new(contig, 10, 20, Strand::Forward).unwrap();
+        let other = OwnedGenomicRegion::new(Arc::new(aliased), 15, 25, Strand::Forward).unwrap();
+        assert!(region.overlaps(&other));
+    }
+
+    #[rstest]
+    fn test_from_genomic_region() {
+        let contig = contig();
+        let region: GenomicRegion<u32, OneBased> =
+            GenomicRegion::new(&contig, 11, 20, Strand::Reverse).unwrap();
+        let mut interner = ContigInterner::new();
+        let owned = interner.to_owned_region(&region);
+        assert_eq!(owned.as_genomic_region(), region);
+        let other = interner.to_owned_region(&region.extend(5, 5));
+        assert!(Arc::ptr_eq(owned.shared_contig(), other.shared_contig()));
+        assert_eq!(interner.len(), 1);
+        assert!(owned < OwnedGenomicRegion::new(contig.clone(), 1, 5, Strand::Reverse).unwrap());
+    }
+
+    #[rstest]
+    fn test_interner_keeps_contig_state() {
+        let contig = contig();
+        let mut other_build = contig.as_ref().clone();
+        other_build.set_ordinal(5);
+        let mut aliased = contig.as_ref().clone();
+        aliased.add_alias("chr1".to_string());
+        let mut circular = contig.as_ref().clone();
+        circular.set_topology(Topology::Circular);
+
+        let mut interner = ContigInterner::new();
+        let shared = interner.intern(&contig);
+        assert!(Arc::ptr_eq(
+            &shared,
+            &interner.intern(&contig.as_ref().clone())
+        ));
+        assert_eq!(interner.intern(&other_build).ordinal(), 5);
+        assert_eq!(interner.intern(&aliased).aliases(), &["chr1".to_string()]);
+        assert!(interner.intern(&circular).is_circular());
+        assert!(!interner.intern(&contig).is_circular());
+        assert_eq!(interner.len(), 4);
+    }
+}
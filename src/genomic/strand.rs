@@ -1,10 +1,15 @@
 use crate::SvartError;
 use std::convert::TryFrom;
 
+/// Strand of a genomic feature.
+///
+/// The coordinates of the features with an `Unknown` strand, such as the unstranded
+/// BED, GFF or VCF records, are interpreted on the forward strand.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Strand {
     Forward,
     Reverse,
+    Unknown,
 }
 
 impl Strand {
@@ -16,10 +21,15 @@ impl Strand {
         *self == Strand::Reverse
     }
 
+    pub fn is_unknown(&self) -> bool {
+        *self == Strand::Unknown
+    }
+
     pub fn opposite(&self) -> Strand {
         match *self {
             Strand::Reverse => Strand::Forward,
             Strand::Forward => Strand::Reverse,
+            Strand::Unknown => Strand::Unknown,
         }
     }
 }
@@ -31,6 +41,7 @@ impl TryFrom<char> for Strand {
         match value {
             '+' => Ok(Strand::Forward),
             '-' => Ok(Strand::Reverse),
+            '.' | '?' => Ok(Strand::Unknown),
             _ => Err(SvartError::IllegalValueError(
                 "Could not parse value for strand.",
             )),
@@ -45,6 +56,7 @@ impl TryFrom<&str> for Strand {
         match value.to_uppercase().as_str() {
             "POS" | "POSITIVE" | "FWD" | "FORWARD" => Ok(Strand::Forward),
             "NEG" | "NEGATIVE" | "REV" | "REVERSE" => Ok(Strand::Reverse),
+            "." | "?" | "UNKNOWN" | "UNSTRANDED" => Ok(Strand::Unknown),
             _ => Err(SvartError::IllegalValueError(
                 "Could not parse value for strand.",
             )),
@@ -57,13 +69,34 @@ impl std::fmt::Display for Strand {
         match *self {
             Strand::Forward => write!(f, "+"),
             Strand::Reverse => write!(f, "-"),
+            Strand::Unknown => write!(f, "."),
+        }
+    }
+}
+
+/// How the strands of the regions are compared by the genomic operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StrandMode {
+    /// Compare the positions of the regions regardless of their strands.
+    Ignore,
+    /// Require the regions to be on the same strand. An unknown strand matches
+    /// only an unknown strand.
+    Strict,
+}
+
+impl StrandMode {
+    /// Check if the regions on the strands `a` and `b` can be compared in this mode.
+    pub fn matches(&self, a: Strand, b: Strand) -> bool {
+        match self {
+            StrandMode::Ignore => true,
+            StrandMode::Strict => a == b,
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::Strand;
+    use super::{Strand, StrandMode};
     use crate::SvartError;
     use rstest::rstest;
     use std::convert::TryFrom;
@@ -85,6 +118,7 @@ mod test {
     #[rstest]
     #[case(Strand::Reverse, "-")]
     #[case(Strand::Forward, "+")]
+    #[case(Strand::Unknown, ".")]
     fn test_correct_symbol(#[case] input: Strand, #[case] expected: String) {
         assert_eq!(format!("{}", input), expected)
     }
@@ -92,6 +126,8 @@ mod test {
     #[rstest]
     #[case('-', Strand::Reverse)]
     #[case('+', Strand::Forward)]
+    #[case('.', Strand::Unknown)]
+    #[case('?', Strand::Unknown)]
     fn test_correct_strand_char_pass(#[case] input: char, #[case] expected: Strand) {
         assert_eq!(Strand::try_from(input).unwrap(), expected);
     }
@@ -110,6 +146,8 @@ mod test {
     #[case("neg", Strand::Reverse)]
     #[case("positive", Strand::Forward)]
     #[case("negative", Strand::Reverse)]
+    #[case(".", Strand::Unknown)]
+    #[case("unstranded", Strand::Unknown)]
     fn test_correct_strand_string_pass(#[case] input: &str, #[case] expected: Strand) {
         assert_eq!(Strand::try_from(input).unwrap(), expected);
     }
@@ -126,7 +164,24 @@ mod test {
     #[rstest]
     #[case(Strand::Reverse, Strand::Forward)]
     #[case(Strand::Forward, Strand::Reverse)]
+    #[case(Strand::Unknown, Strand::Unknown)]
     fn test_opposite_strand(#[case] input: Strand, #[case] expected: Strand) {
         assert_eq!(input.opposite(), expected)
     }
+
+    #[rstest]
+    #[case(StrandMode::Ignore, Strand::Forward, Strand::Reverse, true)]
+    #[case(StrandMode::Ignore, Strand::Unknown, Strand::Reverse, true)]
+    #[case(StrandMode::Strict, Strand::Forward, Strand::Forward, true)]
+    #[case(StrandMode::Strict, Strand::Forward, Strand::Reverse, false)]
+    #[case(StrandMode::Strict, Strand::Unknown, Strand::Forward, false)]
+    #[case(StrandMode::Strict, Strand::Unknown, Strand::Unknown, true)]
+    fn test_strand_mode(
+        #[case] mode: StrandMode,
+        #[case] a: Strand,
+        #[case] b: Strand,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(mode.matches(a, b), expected);
+    }
 }
@@ -0,0 +1,44 @@
+use super::AssignedMoleculeType;
+
+/// Shape of the molecule of a contig.
+///
+/// The regions on a circular contig may wrap around its origin.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Topology {
+    Linear,
+    Circular,
+}
+
+/// The mitochondria, chloroplasts and plasmids are circular, the other molecules are linear.
+impl From<&AssignedMoleculeType> for Topology {
+    fn from(value: &AssignedMoleculeType) -> Self {
+        match value {
+            AssignedMoleculeType::Mitochondrion
+            | AssignedMoleculeType::Chloroplast
+            | AssignedMoleculeType::MitochondrialPlasmid
+            | AssignedMoleculeType::Plasmid => Topology::Circular,
+            _ => Topology::Linear,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Topology;
+    use crate::genomic::AssignedMoleculeType;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(AssignedMoleculeType::Chromosome, Topology::Linear)]
+    #[case(AssignedMoleculeType::Mitochondrion, Topology::Circular)]
+    #[case(AssignedMoleculeType::Chloroplast, Topology::Circular)]
+    #[case(AssignedMoleculeType::MitochondrialPlasmid, Topology::Circular)]
+    #[case(AssignedMoleculeType::Plasmid, Topology::Circular)]
+    #[case(AssignedMoleculeType::Unknown, Topology::Linear)]
+    fn test_from_assigned_molecule_type(
+        #[case] input: AssignedMoleculeType,
+        #[case] expected: Topology,
+    ) {
+        assert_eq!(Topology::from(&input), expected);
+    }
+}
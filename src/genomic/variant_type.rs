@@ -1,4 +1,4 @@
-#[derive(PartialEq)]
+#[derive(Debug, PartialEq)]
 pub enum VariantType {
     Unknown,
     SingleNucleotide,
@@ -95,7 +95,7 @@ impl VariantType {
                 VariantType::Unknown
             };
         }
-        return variant_type;
+        variant_type
     }
 
     pub fn parse_type(refr: &str, alt: &str) -> VariantType {
@@ -110,37 +110,35 @@ impl VariantType {
             return VariantType::MultiNucleotide;
         }
 
-        return if refr.len() < alt.len() {
+        if refr.len() < alt.len() {
             VariantType::Insertion
         } else {
             VariantType::Deletion
-        };
+        }
     }
 
     pub fn is_symbolic_alleles(refr: &str, alt: &str) -> bool {
-        return VariantType::is_symbolic(alt) || VariantType::is_symbolic(refr);
+        VariantType::is_symbolic(alt) || VariantType::is_symbolic(refr)
     }
 
     pub fn is_symbolic(allele: &str) -> bool {
-        return VariantType::is_large_symbolic(allele) || VariantType::is_breakend(allele);
+        VariantType::is_large_symbolic(allele) || VariantType::is_breakend(allele)
     }
 
     pub fn is_breakend(allele: &str) -> bool {
-        return VariantType::is_single_breakend(allele) || VariantType::is_mated_breakend(allele);
+        VariantType::is_single_breakend(allele) || VariantType::is_mated_breakend(allele)
     }
 
     pub fn is_large_symbolic(allele: &str) -> bool {
-        return allele.len() > 1
-            && (allele.chars().next().unwrap() == '<' || allele.chars().last().unwrap() == '>');
+        allele.len() > 1 && (allele.starts_with('<') || allele.ends_with('>'))
     }
 
     pub fn is_single_breakend(allele: &str) -> bool {
-        return allele.len() > 1
-            && (allele.chars().next().unwrap() == '.' || allele.chars().last().unwrap() == '.');
+        allele.len() > 1 && (allele.starts_with('.') || allele.ends_with('.'))
     }
 
     pub fn is_mated_breakend(allele: &str) -> bool {
-        return allele.len() > 1 && (allele.contains("[") || allele.contains("]"));
+        allele.len() > 1 && (allele.contains("[") || allele.contains("]"))
     }
 
     pub fn require_non_symbolic(alt: &str) -> &str {
@@ -177,11 +175,11 @@ impl VariantType {
     }
 
     pub fn is_missing_upstream_deletion(allele: &str) -> bool {
-        return allele.eq("*");
+        allele.eq("*")
     }
 
     pub fn is_missing(allele: &str) -> bool {
-        return allele.eq(".");
+        allele.eq(".")
     }
 
     fn trim_angle_brackets(value: &str) -> &str {
@@ -198,6 +196,12 @@ mod test {
     use rstest::rstest;
 
     #[rstest]
-    #[case("AT", VariantType::SingleNucleotide)]
-    fn test_parse_ref_alt(#[case] input: &str, #[case] expected: VariantType) {}
+    #[case("A", "T", VariantType::SingleNucleotide)]
+    #[case("AT", "GC", VariantType::MultiNucleotide)]
+    #[case("A", "AT", VariantType::Insertion)]
+    #[case("AT", "A", VariantType::Deletion)]
+    #[case("A", "<DEL>", VariantType::Deletion)]
+    fn test_parse_ref_alt(#[case] refr: &str, #[case] alt: &str, #[case] expected: VariantType) {
+        assert_eq!(VariantType::parse_type(refr, alt), expected);
+    }
 }
@@ -0,0 +1,156 @@
+use super::{Contig, GenomicRegion, Strand};
+use crate::ops::Unit;
+use crate::CoordinateSystemMarker;
+use std::marker::PhantomData;
+
+/// An iterator over windows of `size` bases that start `step` bases apart, like `bedtools makewindows`.
+///
+/// The windows are reported on a single strand, from the start to the end of the tiled interval
+/// on that strand. The windows shorter than `size` at the end of the interval are reported only
+/// if `keep_trailing` is `true`. Nothing is reported if `size` or `step` is zero.
+#[derive(Debug, Clone)]
+pub struct Windows<'g, C, S> {
+    contig: &'g Contig<C>,
+    strand: Strand,
+    position: C,
+    end: C,
+    size: C,
+    step: C,
+    keep_trailing: bool,
+    system: PhantomData<S>,
+}
+
+impl<'g, C, S> Windows<'g, C, S>
+where
+    C: Unit,
+{
+    /// Tile the zero-based interval between `start` and `end` on the `strand` of the `contig`.
+    pub(crate) fn new(
+        contig: &'g Contig<C>,
+        strand: Strand,
+        start: C,
+        end: C,
+        size: C,
+        step: C,
+        keep_trailing: bool,
+    ) -> Self {
+        Windows {
+            contig,
+            strand,
+            position: start,
+            end,
+            size,
+            step,
+            keep_trailing,
+            system: PhantomData,
+        }
+    }
+}
+
+impl<'g, C, S> Iterator for Windows<'g, C, S>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+{
+    type Item = GenomicRegion<'g, C, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size <= C::zero() || self.step <= C::zero() || self.position >= self.end {
+            return None;
+        }
+        let start = self.position;
+        let end = match start.checked_add(self.size) {
+            Some(end) if end <= self.end => end,
+            _ => self.end,
+        };
+        if end - start < self.size && !self.keep_trailing {
+            self.position = self.end;
+            return None;
+        }
+        self.position = start.checked_add(self.step).unwrap_or(self.end);
+        Some(GenomicRegion::from_zero_based(
+            self.contig,
+            start,
+            end,
+            self.strand,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::genomic::{
+        AssignedMoleculeType, Contig, Contiged, GenomeBuild, GenomeBuildIdentifier, GenomicRegion,
+        SequenceRole, Strand, Stranded,
+    };
+    use crate::ops::Located;
+    use crate::{CoordinateSystemMarker, OneBased, ZeroBased};
+    use rstest::rstest;
+
+    fn contig(name: &str, length: u32) -> Contig<u32> {
+        Contig::new(
+            name.to_string(),
+            SequenceRole::AssembledMolecule,
+            name.to_string(),
+            AssignedMoleculeType::Chromosome,
+            length,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn coordinates<'g, S>(
+        windows: impl Iterator<Item = GenomicRegion<'g, u32, S>>,
+    ) -> Vec<(u32, u32)>
+    where
+        S: CoordinateSystemMarker,
+    {
+        windows.map(|w| (*w.start(), *w.end())).collect()
+    }
+
+    #[rstest]
+    #[case(10, 10, false, vec![(0, 10), (10, 20)])]
+    #[case(10, 10, true, vec![(0, 10), (10, 20), (20, 25)])]
+    #[case(10, 5, false, vec![(0, 10), (5, 15), (10, 20), (15, 25)])]
+    #[case(10, 5, true, vec![(0, 10), (5, 15), (10, 20), (15, 25), (20, 25)])]
+    #[case(30, 10, false, vec![])]
+    #[case(0, 10, true, vec![])]
+    #[case(10, 0, true, vec![])]
+    fn test_contig_windows(
+        #[case] size: u32,
+        #[case] step: u32,
+        #[case] keep_trailing: bool,
+        #[case] expected: Vec<(u32, u32)>,
+    ) {
+        let contig = contig("1", 25);
+        let windows = contig.windows::<ZeroBased>(size, step, keep_trailing);
+        assert_eq!(coordinates(windows), expected);
+    }
+
+    #[rstest]
+    fn test_region_windows() {
+        let contig = contig("1", 100);
+        let region: GenomicRegion<u32, OneBased> =
+            GenomicRegion::new(&contig, 11, 35, Strand::Reverse).unwrap();
+        let windows: Vec<_> = region.windows(10, 10, true).collect();
+        assert_eq!(
+            coordinates(windows.iter().cloned()),
+            vec![(11, 20), (21, 30), (31, 35)]
+        );
+        assert!(windows.iter().all(|w| w.strand() == Strand::Reverse));
+    }
+
+    #[rstest]
+    fn test_genome_build_windows() {
+        let id = GenomeBuildIdentifier::new("GRCh38".to_string(), "p13".to_string());
+        let build = GenomeBuild::new(id, vec![contig("2", 15), contig("1", 20)]);
+        let windows: Vec<GenomicRegion<u32, ZeroBased>> = build.windows(10, 10, false).collect();
+        let actual: Vec<_> = windows
+            .iter()
+            .map(|w| (w.contig().name(), *w.start(), *w.end()))
+            .collect();
+        assert_eq!(actual, vec![("2", 0, 10), ("1", 0, 10), ("1", 10, 20)]);
+    }
+}
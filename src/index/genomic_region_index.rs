@@ -0,0 +1,470 @@
+use super::RegionIndex;
+use crate::genomic::{Contig, Contiged, Strand, Stranded};
+use crate::ops::func::{contains, segments_distance};
+use crate::ops::{
+    zero_based_on_strand, zero_based_segments_on_strand, DirectedDistance, Located, Spanning, Unit,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Direction of the features reported by [`GenomicRegionIndex::nearest`],
+/// relative to the strand of the query.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Any,
+    Upstream,
+    Downstream,
+}
+
+/// A collection of genomic regions supporting fast overlap queries.
+///
+/// The regions are grouped by contig name and each contig is indexed with a [`RegionIndex`].
+/// Regions are compared by their position on the forward strand, in line with
+/// [`crate::ops::GenomicallyOverlaps`] and [`crate::ops::GenomicallyContains`].
+/// The regions wrapping around the origin of a circular contig are indexed by their segments
+/// on both sides of the origin.
+#[derive(Debug, Clone)]
+pub struct GenomicRegionIndex<C, T> {
+    indices: HashMap<String, RegionIndex<C, T>>,
+    wrapping: HashMap<String, WrappingRegions<C, T>>,
+}
+
+/// The regions of a contig wrapping around its origin.
+#[derive(Debug, Clone)]
+struct WrappingRegions<C, T> {
+    items: Vec<T>,
+    /// The zero-based segments of the items, along with the index of the item.
+    segments: RegionIndex<C, (C, C, usize)>,
+}
+
+impl<C, T> WrappingRegions<C, T>
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+{
+    fn new(items: Vec<T>) -> Self {
+        let segments = items
+            .iter()
+            .enumerate()
+            .flat_map(|(i, item)| {
+                zero_based_segments_on_strand(item, Strand::Forward)
+                    .into_iter()
+                    .map(move |(start, end)| (start, end, i))
+            })
+            .collect();
+        let segments = RegionIndex::with_coordinates(segments, |(start, end, _)| (*start, *end));
+        WrappingRegions { items, segments }
+    }
+
+    fn overlapping(&self, start: C, end: C) -> impl Iterator<Item = &T> {
+        self.segments
+            .overlapping_coordinates(start, end)
+            .into_iter()
+            .map(|(_, _, i)| &self.items[*i])
+    }
+
+    fn containing(&self, start: C, end: C) -> impl Iterator<Item = &T> {
+        self.segments
+            .containing_coordinates(start, end)
+            .into_iter()
+            .map(|(_, _, i)| &self.items[*i])
+    }
+}
+
+impl<C, T> GenomicRegionIndex<C, T>
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+{
+    pub fn new(items: Vec<T>) -> Self {
+        let mut by_contig: HashMap<String, Vec<T>> = HashMap::new();
+        let mut wrapping_by_contig: HashMap<String, Vec<T>> = HashMap::new();
+        for item in items {
+            let (start, end) = zero_based_on_strand(&item, Strand::Forward);
+            let groups = match start > end {
+                true => &mut wrapping_by_contig,
+                false => &mut by_contig,
+            };
+            groups
+                .entry(item.contig().name().to_string())
+                .or_default()
+                .push(item);
+        }
+        let indices = by_contig
+            .into_iter()
+            .map(|(name, items)| {
+                let index = RegionIndex::with_coordinates(items, |item| {
+                    zero_based_on_strand(item, Strand::Forward)
+                });
+                (name, index)
+            })
+            .collect();
+        let wrapping = wrapping_by_contig
+            .into_iter()
+            .map(|(name, items)| (name, WrappingRegions::new(items)))
+            .collect();
+        GenomicRegionIndex { indices, wrapping }
+    }
+
+    /// Get the regions that overlap with the `query`, sorted by their forward strand coordinates.
+    pub fn overlapping<Q>(&self, query: &Q) -> Vec<&T>
+    where
+        Q: Located<C, System = T::System> + Stranded + Contiged<C, Contig = Contig<C>>,
+    {
+        let name = query.contig().name();
+        let mut hits = Vec::new();
+        for (start, end) in zero_based_segments_on_strand(query, Strand::Forward) {
+            if let Some(index) = self.indices.get(name) {
+                hits.extend(index.overlapping_coordinates(start, end));
+            }
+            if let Some(wrapping) = self.wrapping.get(name) {
+                hits.extend(wrapping.overlapping(start, end));
+            }
+        }
+        sorted_hits(hits, query)
+    }
+
+    /// Get the regions that contain the `query`, sorted by their forward strand coordinates.
+    pub fn containing<Q>(&self, query: &Q) -> Vec<&T>
+    where
+        Q: Located<C, System = T::System> + Stranded + Contiged<C, Contig = Contig<C>>,
+    {
+        let name = query.contig().name();
+        let segments = zero_based_segments_on_strand(query, Strand::Forward);
+        if let [(start, end)] = segments[..] {
+            let mut hits = Vec::new();
+            if let Some(index) = self.indices.get(name) {
+                hits.extend(index.containing_coordinates(start, end));
+            }
+            if let Some(wrapping) = self.wrapping.get(name) {
+                hits.extend(wrapping.containing(start, end));
+            }
+            return sorted_hits(hits, query);
+        }
+
+        // A region contains a query wrapping around the origin if it contains both segments.
+        self.overlapping(query)
+            .into_iter()
+            .filter(|item| {
+                let item_segments = zero_based_segments_on_strand(*item, Strand::Forward);
+                segments.iter().all(|(start, end)| {
+                    item_segments
+                        .iter()
+                        .any(|(item_start, item_end)| contains(item_start, item_end, start, end))
+                })
+            })
+            .collect()
+    }
+
+    pub fn count_overlaps<Q>(&self, query: &Q) -> usize
+    where
+        Q: Located<C, System = T::System> + Stranded + Contiged<C, Contig = Contig<C>>,
+    {
+        self.overlapping(query).len()
+    }
+
+    /// Get up to `k` regions nearest to the `query`, sorted by their distance to the `query`.
+    ///
+    /// The distance describes where the region lies relative to the `query`
+    /// on the strand of the `query`. The regions overlapping with the `query` are reported
+    /// with a zero distance unless `ignore_overlapping` is `true`, regardless of the `direction`.
+    /// The ties are broken by the position of the regions on the forward strand.
+    pub fn nearest<Q>(
+        &self,
+        query: &Q,
+        k: usize,
+        direction: Direction,
+        ignore_overlapping: bool,
+    ) -> Vec<(&T, DirectedDistance<C>)>
+    where
+        Q: Located<C, System = T::System> + Stranded + Contiged<C, Contig = Contig<C>>,
+    {
+        let name = query.contig().name();
+        let index = self.indices.get(name);
+        let wrapping = self.wrapping.get(name);
+        if k == 0 || (index.is_none() && wrapping.is_none()) {
+            return vec![];
+        }
+        let query_segments = zero_based_segments_on_strand(query, query.strand());
+        let assess = |item: &'_ T| {
+            let item_segments = zero_based_segments_on_strand(item, query.strand());
+            let distance = segments_distance(&item_segments, &query_segments);
+            let eligible = match distance {
+                DirectedDistance::Overlaps => !ignore_overlapping,
+                DirectedDistance::Upstream(_) => direction != Direction::Downstream,
+                DirectedDistance::Downstream(_) => direction != Direction::Upstream,
+            };
+            eligible.then_some(distance)
+        };
+
+        // The regions wrapping around the origin are few, hence they are all assessed.
+        let wrapping_hits: Vec<_> = wrapping
+            .into_iter()
+            .flat_map(|wrapping| wrapping.items.iter())
+            .filter(|item| item.contig().eq(query.contig()))
+            .filter_map(|item| assess(item).map(|distance| (item, distance)))
+            .collect();
+        let index = match index {
+            Some(index) => index,
+            None => return nearest_hits(wrapping_hits, query, k),
+        };
+        let (start, end) = zero_based_on_strand(query, Strand::Forward);
+        if start > end {
+            let hits = index
+                .iter()
+                .filter_map(|item| assess(item).map(|distance| (item, distance)))
+                .chain(wrapping_hits)
+                .collect();
+            return nearest_hits(hits, query, k);
+        }
+        let length = query.contig().span();
+
+        // Search windows of growing radius around the query. The regions outside of a window
+        // are at least `radius` bases away from the query, hence the search can stop as soon
+        // as the window contains `k` eligible regions or covers the whole contig.
+        let mut radius = C::one();
+        loop {
+            let window_start = start.saturating_sub(radius).max(C::zero());
+            let window_end = end.saturating_add(radius).min(length);
+            let hits: Vec<_> = index
+                .overlapping_coordinates(window_start, window_end)
+                .into_iter()
+                .filter(|item| item.contig().eq(query.contig()))
+                .filter_map(|item| assess(item).map(|distance| (item, distance)))
+                .collect();
+            let close_wrapping_hits = wrapping_hits
+                .iter()
+                .filter(|(_, distance)| distance.abs() < radius)
+                .count();
+
+            let covers_contig = window_start <= C::zero() && length <= window_end;
+            if hits.len() + close_wrapping_hits >= k || covers_contig {
+                let hits = hits.into_iter().chain(wrapping_hits).collect();
+                return nearest_hits(hits, query, k);
+            }
+            radius = radius.saturating_add(radius);
+        }
+    }
+
+    /// Get the index of the regions located on the contig with the given `name`.
+    ///
+    /// The regions wrapping around the origin of a circular contig are not included.
+    pub fn contig_index(&self, name: &str) -> Option<&RegionIndex<C, T>> {
+        self.indices.get(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.values().map(RegionIndex::len).sum::<usize>()
+            + self.wrapping.values().map(|w| w.items.len()).sum::<usize>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Get the `hits` located on the contig of the `query` once, sorted by their forward
+/// strand coordinates.
+fn sorted_hits<'a, C, T, Q>(mut hits: Vec<&'a T>, query: &Q) -> Vec<&'a T>
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+    Q: Contiged<C, Contig = Contig<C>>,
+{
+    let mut seen = HashSet::new();
+    hits.retain(|item| item.contig().eq(query.contig()) && seen.insert(*item as *const T));
+    hits.sort_by_key(|item| zero_based_on_strand(*item, Strand::Forward));
+    hits
+}
+
+/// Get the `k` nearest of the `hits` located on the contig of the `query`, with the ties
+/// broken by the forward strand coordinates.
+fn nearest_hits<'a, C, T, Q>(
+    mut hits: Vec<(&'a T, DirectedDistance<C>)>,
+    query: &Q,
+    k: usize,
+) -> Vec<(&'a T, DirectedDistance<C>)>
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+    Q: Contiged<C, Contig = Contig<C>>,
+{
+    hits.retain(|(item, _)| item.contig().eq(query.contig()));
+    hits.sort_by_key(|(item, distance)| {
+        (distance.abs(), zero_based_on_strand(*item, Strand::Forward))
+    });
+    hits.truncate(k);
+    hits
+}
+
+impl<C, T> FromIterator<T> for GenomicRegionIndex<C, T>
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C, Contig = Contig<C>>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        GenomicRegionIndex::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Direction, GenomicRegionIndex};
+    use crate::genomic::{AssignedMoleculeType, Contig, GenomicRegion, SequenceRole, Strand};
+    use crate::ops::{DirectedDistance, GenomicDistance, GenomicallyOverlaps};
+    use crate::ZeroBased;
+    use rstest::rstest;
+
+    fn contig(name: &str) -> Contig<u32> {
+        Contig::new(
+            name.to_string(),
+            SequenceRole::AssembledMolecule,
+            name.to_string(),
+            AssignedMoleculeType::Chromosome,
+            1_000,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_genomic_region_index() {
+        let one = contig("1");
+        let two = contig("2");
+        let regions: Vec<GenomicRegion<u32, ZeroBased>> = vec![
+            GenomicRegion::new(&one, 100, 200, Strand::Forward).unwrap(),
+            // (100, 150] on the forward strand.
+            GenomicRegion::new(&one, 850, 900, Strand::Reverse).unwrap(),
+            GenomicRegion::new(&one, 300, 400, Strand::Forward).unwrap(),
+            GenomicRegion::new(&two, 100, 200, Strand::Forward).unwrap(),
+        ];
+        let index: GenomicRegionIndex<u32, _> = regions.iter().cloned().collect();
+        assert_eq!(index.len(), 4);
+
+        let query: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&one, 120, 130, Strand::Forward).unwrap();
+        let expected: Vec<_> = regions.iter().filter(|r| r.overlaps(&query)).collect();
+        assert_eq!(expected.len(), 2);
+        assert_eq!(index.overlapping(&query), vec![&regions[1], &regions[0]]);
+        assert_eq!(index.count_overlaps(&query), 2);
+        assert_eq!(index.containing(&query).len(), 2);
+
+        // (840, 880] on the reverse strand is (120, 160] on the forward strand.
+        let query: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&one, 840, 880, Strand::Reverse).unwrap();
+        assert_eq!(index.count_overlaps(&query), 2);
+        assert_eq!(index.containing(&query), vec![&regions[0]]);
+
+        let three = contig("3");
+        let query: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&three, 100, 200, Strand::Forward).unwrap();
+        assert!(index.overlapping(&query).is_empty());
+    }
+
+    #[rstest]
+    #[case(Direction::Any, false, vec![(1, DirectedDistance::Overlaps), (2, DirectedDistance::Downstream(50)), (0, DirectedDistance::Upstream(100))])]
+    #[case(Direction::Any, true, vec![(2, DirectedDistance::Downstream(50)), (0, DirectedDistance::Upstream(100)), (3, DirectedDistance::Downstream(450))])]
+    #[case(Direction::Upstream, true, vec![(0, DirectedDistance::Upstream(100))])]
+    #[case(Direction::Downstream, true, vec![(2, DirectedDistance::Downstream(50)), (3, DirectedDistance::Downstream(450))])]
+    fn test_nearest(
+        #[case] direction: Direction,
+        #[case] ignore_overlapping: bool,
+        #[case] expected: Vec<(usize, DirectedDistance<u32>)>,
+    ) {
+        let one = contig("1");
+        let regions: Vec<GenomicRegion<u32, ZeroBased>> = vec![
+            GenomicRegion::new(&one, 100, 200, Strand::Forward).unwrap(),
+            GenomicRegion::new(&one, 290, 310, Strand::Forward).unwrap(),
+            GenomicRegion::new(&one, 450, 460, Strand::Forward).unwrap(),
+            GenomicRegion::new(&one, 850, 900, Strand::Forward).unwrap(),
+        ];
+        let index: GenomicRegionIndex<u32, _> = regions.iter().cloned().collect();
+        let query: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&one, 300, 400, Strand::Forward).unwrap();
+
+        let nearest = index.nearest(&query, 3, direction, ignore_overlapping);
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(i, distance)| (&regions[i], distance))
+            .collect();
+        assert_eq!(nearest, expected);
+        for (region, distance) in nearest {
+            assert_eq!(region.distance(&query), Some(distance));
+        }
+    }
+
+    #[rstest]
+    fn test_wrapping_regions() {
+        let mt = Contig::new(
+            "MT".to_string(),
+            SequenceRole::AssembledMolecule,
+            "MT".to_string(),
+            AssignedMoleculeType::Mitochondrion,
+            100,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap();
+        let region = |start, end| -> GenomicRegion<u32, ZeroBased> {
+            GenomicRegion::new(&mt, start, end, Strand::Forward).unwrap()
+        };
+        // (90, 100] and (0, 10] on the forward strand.
+        let regions = [region(90, 10), region(20, 30), region(50, 60)];
+        let index: GenomicRegionIndex<u32, _> = regions.iter().cloned().collect();
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.contig_index("MT").map(|i| i.len()), Some(2));
+
+        assert_eq!(
+            index.overlapping(&region(5, 30)),
+            vec![&regions[1], &regions[0]]
+        );
+        assert_eq!(index.overlapping(&region(95, 5)), vec![&regions[0]]);
+        assert_eq!(index.count_overlaps(&region(85, 55)), 3);
+        assert_eq!(index.containing(&region(5, 8)), vec![&regions[0]]);
+        assert_eq!(index.containing(&region(95, 5)), vec![&regions[0]]);
+        assert!(index.containing(&region(95, 25)).is_empty());
+
+        assert_eq!(
+            index.nearest(&region(40, 45), 3, Direction::Any, false),
+            vec![
+                (&regions[2], DirectedDistance::Downstream(5)),
+                (&regions[1], DirectedDistance::Upstream(10)),
+                (&regions[0], DirectedDistance::Upstream(30)),
+            ]
+        );
+        assert_eq!(
+            index.nearest(&region(95, 5), 2, Direction::Any, false),
+            vec![
+                (&regions[0], DirectedDistance::Overlaps),
+                (&regions[1], DirectedDistance::Downstream(15)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_nearest_on_reverse_strand() {
+        let one = contig("1");
+        let regions: Vec<GenomicRegion<u32, ZeroBased>> = vec![
+            GenomicRegion::new(&one, 100, 200, Strand::Forward).unwrap(),
+            GenomicRegion::new(&one, 500, 600, Strand::Forward).unwrap(),
+        ];
+        let index: GenomicRegionIndex<u32, _> = regions.iter().cloned().collect();
+        // (300, 400] on the forward strand.
+        let query: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&one, 600, 700, Strand::Reverse).unwrap();
+
+        let nearest = index.nearest(&query, 1, Direction::Upstream, false);
+        assert_eq!(
+            nearest,
+            vec![(&regions[1], DirectedDistance::Upstream(100))]
+        );
+        let nearest = index.nearest(&query, 5, Direction::Downstream, false);
+        assert_eq!(
+            nearest,
+            vec![(&regions[0], DirectedDistance::Downstream(100))]
+        );
+        assert!(index.nearest(&query, 0, Direction::Any, false).is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+mod genomic_region_index;
+mod region_index;
+
+pub use genomic_region_index::{Direction, GenomicRegionIndex};
+pub use region_index::RegionIndex;
@@ -0,0 +1,342 @@
+use crate::ops::func::{contains, overlaps};
+use crate::ops::{Located, Unit};
+use crate::CoordinateSystem;
+
+/// Number of tree levels below which the subtree is scanned linearly.
+const LINEAR_SCAN_LEVEL: usize = 3;
+
+/// An immutable collection of regions supporting fast overlap queries.
+///
+/// The regions are sorted by their zero-based coordinates and indexed with an implicit
+/// augmented interval tree. The index is built in `O(n log n)` and answers queries
+/// in `O(log n + k)` where `k` is the number of reported regions.
+#[derive(Debug, Clone)]
+pub struct RegionIndex<C, T> {
+    items: Vec<T>,
+    starts: Vec<C>,
+    ends: Vec<C>,
+    max_ends: Vec<C>,
+    max_level: usize,
+}
+
+impl<C, T> RegionIndex<C, T>
+where
+    C: Unit,
+    T: Located<C>,
+{
+    pub fn new(items: Vec<T>) -> Self {
+        let cs = CoordinateSystem::zero_based();
+        RegionIndex::with_coordinates(items, |item| {
+            (
+                item.start_with_coordinate_system(cs),
+                item.end_with_coordinate_system(cs),
+            )
+        })
+    }
+
+    /// Get the regions that overlap with the `query`, sorted by their coordinates.
+    pub fn overlapping<Q>(&self, query: &Q) -> Vec<&T>
+    where
+        Q: Located<C, System = T::System>,
+    {
+        let (start, end) = zero_based(query);
+        self.overlapping_coordinates(start, end)
+    }
+
+    /// Get the regions that contain the `query`, sorted by their coordinates.
+    pub fn containing<Q>(&self, query: &Q) -> Vec<&T>
+    where
+        Q: Located<C, System = T::System>,
+    {
+        let (start, end) = zero_based(query);
+        self.containing_coordinates(start, end)
+    }
+
+    pub fn count_overlaps<Q>(&self, query: &Q) -> usize
+    where
+        Q: Located<C, System = T::System>,
+    {
+        let (start, end) = zero_based(query);
+        let mut count = 0;
+        self.visit_candidates(&start, &end, |i| {
+            if overlaps(&self.starts[i], &self.ends[i], &start, &end) {
+                count += 1;
+            }
+        });
+        count
+    }
+}
+
+impl<C, T> RegionIndex<C, T>
+where
+    C: Unit,
+{
+    /// Build the index using zero-based `coordinates` of the items.
+    pub(crate) fn with_coordinates<F>(items: Vec<T>, coordinates: F) -> Self
+    where
+        F: Fn(&T) -> (C, C),
+    {
+        let mut entries: Vec<_> = items
+            .into_iter()
+            .map(|item| {
+                let (start, end) = coordinates(&item);
+                (start, end, item)
+            })
+            .collect();
+        entries.sort_by_key(|(start, end, _)| (*start, *end));
+
+        let mut starts = Vec::with_capacity(entries.len());
+        let mut ends = Vec::with_capacity(entries.len());
+        let mut items = Vec::with_capacity(entries.len());
+        for (start, end, item) in entries {
+            starts.push(start);
+            ends.push(end);
+            items.push(item);
+        }
+        let (max_ends, max_level) = index_max_ends(&ends);
+
+        RegionIndex {
+            items,
+            starts,
+            ends,
+            max_ends,
+            max_level,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterate over the regions sorted by their coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    /// Get the regions that overlap with the zero-based `start` and `end` coordinates.
+    pub(crate) fn overlapping_coordinates(&self, start: C, end: C) -> Vec<&T> {
+        let mut hits = Vec::new();
+        self.visit_candidates(&start, &end, |i| {
+            if overlaps(&self.starts[i], &self.ends[i], &start, &end) {
+                hits.push(&self.items[i]);
+            }
+        });
+        hits
+    }
+
+    /// Get the regions that contain the zero-based `start` and `end` coordinates.
+    pub(crate) fn containing_coordinates(&self, start: C, end: C) -> Vec<&T> {
+        let mut hits = Vec::new();
+        self.visit_candidates(&start, &end, |i| {
+            if contains(&self.starts[i], &self.ends[i], &start, &end) {
+                hits.push(&self.items[i]);
+            }
+        });
+        hits
+    }
+
+    /// Call `visitor` with the index of every region with `region.start <= end`
+    /// and `start <= region.end`, in sorted order.
+    ///
+    /// The closed comparisons make sure that empty regions are visited as well.
+    fn visit_candidates<F>(&self, start: &C, end: &C, mut visitor: F)
+    where
+        F: FnMut(usize),
+    {
+        let n = self.items.len();
+        if n == 0 {
+            return;
+        }
+        // (node, level, whether the left subtree has been visited)
+        let mut stack = vec![((1usize << self.max_level) - 1, self.max_level, false)];
+        while let Some((x, k, visited)) = stack.pop() {
+            if k <= LINEAR_SCAN_LEVEL {
+                let i0 = x >> k << k;
+                let i1 = (i0 + (1 << (k + 1)) - 1).min(n);
+                for i in i0..i1 {
+                    if self.starts[i] > *end {
+                        break;
+                    }
+                    if *start <= self.ends[i] {
+                        visitor(i);
+                    }
+                }
+            } else if !visited {
+                let left = x - (1 << (k - 1));
+                stack.push((x, k, true));
+                if left >= n || *start <= self.max_ends[left] {
+                    stack.push((left, k - 1, false));
+                }
+            } else if x < n && self.starts[x] <= *end {
+                if *start <= self.ends[x] {
+                    visitor(x);
+                }
+                stack.push((x + (1 << (k - 1)), k - 1, false));
+            }
+        }
+    }
+}
+
+impl<C, T> FromIterator<T> for RegionIndex<C, T>
+where
+    C: Unit,
+    T: Located<C>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        RegionIndex::new(iter.into_iter().collect())
+    }
+}
+
+fn zero_based<C, T>(region: &T) -> (C, C)
+where
+    C: Unit,
+    T: Located<C>,
+{
+    let cs = CoordinateSystem::zero_based();
+    (
+        region.start_with_coordinate_system(cs),
+        region.end_with_coordinate_system(cs),
+    )
+}
+
+/// Compute the maximum end coordinate of each subtree of the implicit interval tree
+/// laid over `ends`, and the level of the root node.
+fn index_max_ends<C>(ends: &[C]) -> (Vec<C>, usize)
+where
+    C: Unit,
+{
+    let n = ends.len();
+    let mut max_ends = ends.to_vec();
+    if n == 0 {
+        return (max_ends, 0);
+    }
+
+    let mut last_i = (n - 1) & !1;
+    let mut last = ends[last_i];
+    let mut k = 1;
+    while (1usize << k) <= n {
+        let x = 1usize << (k - 1);
+        let i0 = (x << 1) - 1;
+        let step = x << 2;
+        let mut i = i0;
+        while i < n {
+            let left = max_ends[i - x];
+            let right = if i + x < n { max_ends[i + x] } else { last };
+            max_ends[i] = ends[i].max(left).max(right);
+            i += step;
+        }
+        last_i = if (last_i >> k) & 1 == 1 {
+            last_i - x
+        } else {
+            last_i + x
+        };
+        if last_i < n && max_ends[last_i] > last {
+            last = max_ends[last_i];
+        }
+        k += 1;
+    }
+    (max_ends, k - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::RegionIndex;
+    use crate::ops::{Contains, Located, Overlaps};
+    use crate::{OneBased, Region, ZeroBased};
+    use rstest::rstest;
+
+    fn regions() -> Vec<Region<u32, ZeroBased>> {
+        (0..1000u32)
+            .map(|i| {
+                let start = (i * 7919) % 10_000;
+                Region::new(start, start + (i * 31) % 500).unwrap()
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(0, 10)]
+    #[case(500, 510)]
+    #[case(2_000, 4_000)]
+    #[case(9_990, 10_000)]
+    #[case(5_000, 5_000)]
+    #[case(20_000, 30_000)]
+    fn test_overlapping_matches_linear_scan(#[case] start: u32, #[case] end: u32) {
+        let regions = regions();
+        let index: RegionIndex<u32, _> = regions.iter().cloned().collect();
+        let query: Region<u32, ZeroBased> = Region::new(start, end).unwrap();
+
+        let mut expected: Vec<_> = regions.iter().filter(|r| r.overlaps(&query)).collect();
+        expected.sort();
+        assert_eq!(index.overlapping(&query), expected);
+        assert_eq!(index.count_overlaps(&query), expected.len());
+
+        let mut expected: Vec<_> = regions.iter().filter(|r| r.contains(&query)).collect();
+        expected.sort();
+        assert_eq!(index.containing(&query), expected);
+    }
+
+    #[rstest]
+    fn test_index_sizes() {
+        for n in 1..70u32 {
+            let regions: Vec<Region<u32, ZeroBased>> = (0..n)
+                .map(|i| Region::new(i * 3, i * 3 + (i % 7) * 5).unwrap())
+                .collect();
+            let index = RegionIndex::new(regions.clone());
+            for start in 0..n * 3 + 40 {
+                let query = Region::new(start, start + 2).unwrap();
+                let expected: Vec<_> = regions.iter().filter(|r| r.overlaps(&query)).collect();
+                assert_eq!(index.overlapping(&query), expected);
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_empty_regions() {
+        let index = RegionIndex::new(vec![
+            Region::<u32, ZeroBased>::new(10, 10).unwrap(),
+            Region::new(10, 20).unwrap(),
+            Region::new(20, 20).unwrap(),
+        ]);
+        let query = Region::new(10, 10).unwrap();
+        assert_eq!(
+            index.overlapping(&query),
+            vec![&Region::new(10, 10).unwrap()]
+        );
+
+        let query = Region::new(15, 15).unwrap();
+        assert_eq!(
+            index.overlapping(&query),
+            vec![&Region::new(10, 20).unwrap()]
+        );
+    }
+
+    #[rstest]
+    fn test_one_based() {
+        let index = RegionIndex::new(vec![
+            Region::<u32, OneBased>::new(1, 10).unwrap(),
+            Region::new(11, 20).unwrap(),
+        ]);
+        let query = Region::new(10, 10).unwrap();
+        assert_eq!(
+            index.overlapping(&query),
+            vec![&Region::new(1, 10).unwrap()]
+        );
+        assert_eq!(index.len(), 2);
+        assert_eq!(
+            index.iter().map(|r| *r.start()).collect::<Vec<_>>(),
+            vec![1, 11]
+        );
+    }
+
+    #[rstest]
+    fn test_empty_index() {
+        let index: RegionIndex<u32, Region<u32, ZeroBased>> = RegionIndex::new(vec![]);
+        assert!(index.is_empty());
+        assert!(index.overlapping(&Region::new(0, 10).unwrap()).is_empty());
+    }
+}
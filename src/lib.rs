@@ -1,8 +1,13 @@
+pub mod bound;
+pub mod coordinate_system;
 pub mod err;
 mod experimental;
 pub mod genomic;
+pub mod index;
 pub mod ops;
 pub mod region;
 
+pub use self::bound::*;
+pub use self::coordinate_system::*;
 pub use self::err::*;
 pub use self::region::*;
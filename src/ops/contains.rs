@@ -1,5 +1,7 @@
-use crate::genomic::{Contiged, Stranded};
-use crate::ops::{contains, Located, Transposable, Unit};
+use crate::genomic::{Contiged, StrandMode, Stranded};
+use crate::ops::transposable::zero_based_segments_on_strand;
+use crate::ops::{contains, Located, Unit};
+use crate::CoordinateSystem;
 
 pub trait Contains<C, O = Self>
 where
@@ -8,21 +10,33 @@ where
     fn contains(&self, other: &O) -> bool;
 }
 
-impl<C, T> Contains<C> for T
+impl<C, T, O> Contains<C, O> for T
 where
     C: Unit,
     T: Located<C>,
+    O: Located<C, System = T::System>,
 {
-    fn contains(&self, other: &Self) -> bool {
-        contains(self.start(), self.end(), other.start(), other.end())
+    fn contains(&self, other: &O) -> bool {
+        let cs = CoordinateSystem::zero_based();
+        contains(
+            &self.start_with_coordinate_system(cs),
+            &self.end_with_coordinate_system(cs),
+            &other.start_with_coordinate_system(cs),
+            &other.end_with_coordinate_system(cs),
+        )
     }
 }
 
+/// The genomic regions are compared on the strand of `self`, regardless of the strand
+/// of `other`, unless [`StrandMode::Strict`] is used. The regions wrapping around the origin
+/// of a circular contig are compared piecewise.
 pub trait GenomicallyContains<C, O = Self>
 where
     O: ?Sized,
 {
     fn contains(&self, other: &O) -> bool;
+
+    fn contains_with_mode(&self, other: &O, mode: StrandMode) -> bool;
 }
 
 impl<C, T> GenomicallyContains<C> for T
@@ -31,23 +45,28 @@ where
     T: Located<C> + Stranded + Contiged<C>,
 {
     fn contains(&self, other: &Self) -> bool {
-        if self.contig().ne(other.contig()) {
-            return false;
-        }
+        self.contains_with_mode(other, StrandMode::Ignore)
+    }
 
-        if self.strand().eq(&other.strand()) {
-            return contains(self.start(), self.end(), other.start(), other.end());
+    fn contains_with_mode(&self, other: &Self, mode: StrandMode) -> bool {
+        if self.contig().ne(other.contig()) || !mode.matches(self.strand(), other.strand()) {
+            return false;
         }
 
-        let other_start = other.start_on_strand(self.strand());
-        let other_end = other.end_on_strand(self.strand());
-        contains(self.start(), self.end(), &other_start, &other_end)
+        let segments = zero_based_segments_on_strand(self, self.strand());
+        let other_segments = zero_based_segments_on_strand(other, self.strand());
+        other_segments.iter().all(|(other_start, other_end)| {
+            segments
+                .iter()
+                .any(|(start, end)| contains(start, end, other_start, other_end))
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Contains, Located};
+    use crate::ZeroBased;
     use rstest::rstest;
 
     struct TestRegion {
@@ -56,6 +75,8 @@ mod test {
     }
 
     impl Located<u8> for TestRegion {
+        type System = ZeroBased;
+
         fn start(&self) -> &u8 {
             &self.start
         }
@@ -0,0 +1,225 @@
+use crate::genomic::{Contiged, Stranded};
+use crate::ops::func::segments_distance;
+use crate::ops::transposable::zero_based_segments_on_strand;
+use crate::ops::{distance, Located, Unit};
+use crate::CoordinateSystem;
+
+/// Position of a region relative to another region.
+///
+/// The variants describe where `self` lies with respect to `other`, e.g. `Upstream(5)` means
+/// that there are 5 bases between the end of `self` and the start of `other`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DirectedDistance<C> {
+    Upstream(C),
+    Downstream(C),
+    Overlaps,
+}
+
+impl<C> DirectedDistance<C>
+where
+    C: Unit,
+{
+    pub fn is_upstream(&self) -> bool {
+        matches!(self, DirectedDistance::Upstream(_))
+    }
+
+    pub fn is_downstream(&self) -> bool {
+        matches!(self, DirectedDistance::Downstream(_))
+    }
+
+    pub fn is_overlapping(&self) -> bool {
+        matches!(self, DirectedDistance::Overlaps)
+    }
+
+    /// Get the number of bases between the regions, regardless of direction.
+    pub fn abs(&self) -> C {
+        match *self {
+            DirectedDistance::Upstream(d) | DirectedDistance::Downstream(d) => d,
+            DirectedDistance::Overlaps => C::zero(),
+        }
+    }
+}
+
+pub trait Distance<C, O = Self>
+where
+    O: ?Sized,
+{
+    fn distance(&self, other: &O) -> DirectedDistance<C>;
+}
+
+impl<C, T, O> Distance<C, O> for T
+where
+    C: Unit,
+    T: Located<C>,
+    O: Located<C, System = T::System>,
+{
+    fn distance(&self, other: &O) -> DirectedDistance<C> {
+        let cs = CoordinateSystem::zero_based();
+        distance(
+            &self.start_with_coordinate_system(cs),
+            &self.end_with_coordinate_system(cs),
+            &other.start_with_coordinate_system(cs),
+            &other.end_with_coordinate_system(cs),
+        )
+    }
+}
+
+pub trait GenomicDistance<C, O = Self>
+where
+    O: ?Sized,
+{
+    /// Get the distance on the strand of `self`, or `None` if the regions are on different contigs.
+    fn distance(&self, other: &O) -> Option<DirectedDistance<C>>;
+}
+
+impl<C, T> GenomicDistance<C> for T
+where
+    C: Unit,
+    T: Located<C> + Stranded + Contiged<C>,
+{
+    fn distance(&self, other: &Self) -> Option<DirectedDistance<C>> {
+        if self.contig().ne(other.contig()) {
+            return None;
+        }
+
+        // The regions wrapping around the origin of a circular contig are measured by the
+        // closest pair of segments.
+        let segments = zero_based_segments_on_strand(self, self.strand());
+        let other_segments = zero_based_segments_on_strand(other, self.strand());
+        Some(segments_distance(&segments, &other_segments))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DirectedDistance, Distance, GenomicDistance};
+    use crate::genomic::{AssignedMoleculeType, Contig, GenomicRegion, SequenceRole, Strand};
+    use crate::ops::Located;
+    use crate::ZeroBased;
+    use rstest::rstest;
+
+    struct TestRegion {
+        start: u8,
+        end: u8,
+    }
+
+    impl Located<u8> for TestRegion {
+        type System = ZeroBased;
+
+        fn start(&self) -> &u8 {
+            &self.start
+        }
+
+        fn end(&self) -> &u8 {
+            &self.end
+        }
+    }
+
+    #[rstest]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 15, end: 20 }, DirectedDistance::Upstream(5))]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 10, end: 20 }, DirectedDistance::Upstream(0))]
+    #[case(TestRegion { start: 25, end: 30 }, TestRegion { start: 15, end: 20 }, DirectedDistance::Downstream(5))]
+    #[case(TestRegion { start: 5, end: 15 }, TestRegion { start: 10, end: 20 }, DirectedDistance::Overlaps)]
+    #[case(TestRegion { start: 10, end: 10 }, TestRegion { start: 10, end: 10 }, DirectedDistance::Overlaps)]
+    #[case(TestRegion { start: 12, end: 12 }, TestRegion { start: 10, end: 10 }, DirectedDistance::Downstream(2))]
+    fn test_distance(
+        #[case] region_1: TestRegion,
+        #[case] region_2: TestRegion,
+        #[case] expected: DirectedDistance<u8>,
+    ) {
+        assert_eq!(region_1.distance(&region_2), expected);
+        assert_eq!(region_1.distance(&region_2).abs(), expected.abs());
+    }
+
+    fn get_contig(name: &str) -> Contig<u32> {
+        Contig::new(
+            name.to_string(),
+            SequenceRole::AssembledMolecule,
+            name.to_string(),
+            AssignedMoleculeType::Chromosome,
+            100,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_genomic_distance() {
+        let contig = get_contig("1");
+        let variant: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 10, 11, Strand::Forward).unwrap();
+        let gene: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 20, 40, Strand::Forward).unwrap();
+        assert_eq!(
+            GenomicDistance::distance(&variant, &gene),
+            Some(DirectedDistance::Upstream(9))
+        );
+        assert_eq!(
+            GenomicDistance::distance(&gene, &variant),
+            Some(DirectedDistance::Downstream(9))
+        );
+
+        // On the reverse strand, the variant is at (89, 90], past the end of the gene at (60, 80].
+        let reverse_gene: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 60, 80, Strand::Reverse).unwrap();
+        assert_eq!(
+            GenomicDistance::distance(&reverse_gene, &variant),
+            Some(DirectedDistance::Upstream(9))
+        );
+        assert_eq!(
+            GenomicDistance::distance(&variant, &reverse_gene),
+            Some(DirectedDistance::Upstream(9))
+        );
+    }
+
+    #[rstest]
+    fn test_genomic_distance_wrapping() {
+        let contig = Contig::new(
+            "MT".to_string(),
+            SequenceRole::AssembledMolecule,
+            "MT".to_string(),
+            AssignedMoleculeType::Mitochondrion,
+            100,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap();
+        // (90, 100] and (0, 10] on the forward strand.
+        let wrapping: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 90, 10, Strand::Forward).unwrap();
+        let after: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 20, 30, Strand::Forward).unwrap();
+        let overlapping: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&contig, 5, 30, Strand::Forward).unwrap();
+        assert_eq!(
+            GenomicDistance::distance(&wrapping, &after),
+            Some(DirectedDistance::Upstream(10))
+        );
+        assert_eq!(
+            GenomicDistance::distance(&after, &wrapping),
+            Some(DirectedDistance::Downstream(10))
+        );
+        assert_eq!(
+            GenomicDistance::distance(&wrapping, &overlapping),
+            Some(DirectedDistance::Overlaps)
+        );
+        assert_eq!(
+            GenomicDistance::distance(&wrapping, &wrapping),
+            Some(DirectedDistance::Overlaps)
+        );
+    }
+
+    #[rstest]
+    fn test_genomic_distance_different_contigs() {
+        let one = get_contig("1");
+        let two = get_contig("2");
+        let a: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&one, 10, 20, Strand::Forward).unwrap();
+        let b: GenomicRegion<u32, ZeroBased> =
+            GenomicRegion::new(&two, 10, 20, Strand::Forward).unwrap();
+        assert_eq!(GenomicDistance::distance(&a, &b), None);
+    }
+}
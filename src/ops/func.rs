@@ -1,11 +1,12 @@
-use crate::ops::Unit;
+use crate::ops::{DirectedDistance, Unit};
+use crate::CoordinateSystem;
 
 #[inline(always)]
 pub fn is_empty<C>(start: &C, end: &C) -> bool
 where
     C: Unit,
 {
-    (*end - *start).is_zero()
+    end <= start
 }
 
 #[inline(always)]
@@ -28,32 +29,205 @@ where
     a_start <= b_start && b_end <= a_end
 }
 
-// #[inline(always)]
-// fn distance<C>(a_start: &C, a_end: &C, b_start: &C, b_end: &C) -> DirectedDistance<C>
-// where
-//     C: Unit,
-// {
-//     if overlaps_with(a_start, a_end, b_start, b_end) {
-//         DirectedDistance::Overlaps
-//     } else {
-//         match a_end.cmp(b_start) {
-//             Ordering::Less => {
-//                 // a is upstream of b.
-//                 DirectedDistance::Upstream(*b_start - *a_end)
-//             }
-//             Ordering::Equal => {
-//                 match a_start.cmp(b_end) {
-//                     Ordering::Less => DirectedDistance::Upstream(C::zero()),
-//                     Ordering::Equal => panic!(
-//                         "Both coordinates are equal should have been caught with the previous `overlaps_with` test!"
-//                     ),
-//                     Ordering::Greater => DirectedDistance::Downstream(C::zero()),
-//                 }
-//             },
-//             Ordering::Greater => {
-//                 // a is downstream of b.
-//                 DirectedDistance::Downstream(*a_start - *b_end)
-//             }
-//         }
-//     }
-// }
+#[inline(always)]
+pub fn overlap_length<C>(a_start: &C, a_end: &C, b_start: &C, b_end: &C) -> C
+where
+    C: Unit,
+{
+    match intersection(a_start, a_end, b_start, b_end) {
+        Some((start, end)) => end - start,
+        None => C::zero(),
+    }
+}
+
+#[inline(always)]
+pub fn intersection<C>(a_start: &C, a_end: &C, b_start: &C, b_end: &C) -> Option<(C, C)>
+where
+    C: Unit,
+{
+    if overlaps(a_start, a_end, b_start, b_end) {
+        Some((*a_start.max(b_start), *a_end.min(b_end)))
+    } else {
+        None
+    }
+}
+
+#[inline(always)]
+pub fn hull<C>(a_start: &C, a_end: &C, b_start: &C, b_end: &C) -> (C, C)
+where
+    C: Unit,
+{
+    (*a_start.min(b_start), *a_end.max(b_end))
+}
+
+/// Get the union of two regions, or `None` if the regions neither overlap nor abut each other.
+#[inline(always)]
+pub fn union<C>(a_start: &C, a_end: &C, b_start: &C, b_end: &C) -> Option<(C, C)>
+where
+    C: Unit,
+{
+    if overlaps(a_start, a_end, b_start, b_end) || a_end == b_start || b_end == a_start {
+        Some(hull(a_start, a_end, b_start, b_end))
+    } else {
+        None
+    }
+}
+
+/// Get the parts of region `a` that are not covered by region `b`.
+#[inline(always)]
+pub fn difference<C>(a_start: &C, a_end: &C, b_start: &C, b_end: &C) -> Vec<(C, C)>
+where
+    C: Unit,
+{
+    if !overlaps(a_start, a_end, b_start, b_end) {
+        return vec![(*a_start, *a_end)];
+    }
+    let mut pieces = Vec::with_capacity(2);
+    if a_start < b_start {
+        pieces.push((*a_start, *b_start));
+    }
+    if b_end < a_end {
+        pieces.push((*b_end, *a_end));
+    }
+    pieces
+}
+
+/// Merge the overlapping or abutting zero-based `pieces` and, on a circular sequence of
+/// the given length, join the pieces touching the origin into a single wrapping region
+/// with the start after the end.
+pub(crate) fn join_at_origin<C>(mut pieces: Vec<(C, C)>, circular_length: Option<C>) -> Vec<(C, C)>
+where
+    C: Unit,
+{
+    pieces.sort_unstable();
+    let mut merged: Vec<(C, C)> = Vec::with_capacity(pieces.len());
+    for (start, end) in pieces {
+        match merged.last_mut() {
+            Some((_, current_end)) if start <= *current_end => {
+                *current_end = end.max(*current_end);
+            }
+            _ => merged.push((start, end)),
+        }
+    }
+    if let Some(length) = circular_length {
+        let wraps = merged.len() > 1
+            && merged[0].0.is_zero()
+            && merged.last().is_some_and(|(_, end)| *end == length);
+        if wraps {
+            let (_, end) = merged.remove(0);
+            if let Some(last) = merged.last_mut() {
+                last.1 = end;
+            }
+        }
+    }
+    merged
+}
+
+#[inline(always)]
+fn apply_delta<C>(value: C, delta: i8) -> C
+where
+    C: Unit,
+{
+    match delta {
+        0 => value,
+        d if d > 0 => value.saturating_add(C::one()),
+        _ => value.saturating_sub(C::one()),
+    }
+}
+
+#[inline(always)]
+fn checked_apply_delta<C>(value: C, delta: i8) -> Option<C>
+where
+    C: Unit,
+{
+    match delta {
+        0 => Some(value),
+        d if d > 0 => value.checked_add(C::one()),
+        _ => value.checked_sub(C::one()),
+    }
+}
+
+/// Convert a start coordinate like [`start_with_coordinate_system`], or get `None` if the start
+/// is not representable by `C` in the `target` coordinate system, e.g. a one-based start of `0u32`.
+#[inline(always)]
+pub(crate) fn checked_start_with_coordinate_system<C>(
+    start: C,
+    current: CoordinateSystem,
+    target: CoordinateSystem,
+) -> Option<C>
+where
+    C: Unit,
+{
+    checked_apply_delta(start, current.start_delta(target))
+}
+
+/// Convert an end coordinate like [`end_with_coordinate_system`], or get `None` if the end
+/// is not representable by `C` in the `target` coordinate system.
+#[inline(always)]
+pub(crate) fn checked_end_with_coordinate_system<C>(
+    end: C,
+    current: CoordinateSystem,
+    target: CoordinateSystem,
+) -> Option<C>
+where
+    C: Unit,
+{
+    checked_apply_delta(end, current.end_delta(target))
+}
+
+/// Convert a start coordinate from the `current` to the `target` coordinate system.
+#[inline(always)]
+pub fn start_with_coordinate_system<C>(
+    start: C,
+    current: CoordinateSystem,
+    target: CoordinateSystem,
+) -> C
+where
+    C: Unit,
+{
+    apply_delta(start, current.start_delta(target))
+}
+
+/// Convert an end coordinate from the `current` to the `target` coordinate system.
+#[inline(always)]
+pub fn end_with_coordinate_system<C>(
+    end: C,
+    current: CoordinateSystem,
+    target: CoordinateSystem,
+) -> C
+where
+    C: Unit,
+{
+    apply_delta(end, current.end_delta(target))
+}
+
+#[inline(always)]
+pub fn distance<C>(a_start: &C, a_end: &C, b_start: &C, b_end: &C) -> DirectedDistance<C>
+where
+    C: Unit,
+{
+    if overlaps(a_start, a_end, b_start, b_end) {
+        DirectedDistance::Overlaps
+    } else if a_end <= b_start {
+        // a is upstream of b.
+        DirectedDistance::Upstream(*b_start - *a_end)
+    } else {
+        // a is downstream of b.
+        DirectedDistance::Downstream(*a_start - *b_end)
+    }
+}
+
+/// Get the distance between the closest pair of the zero-based `segments` of two regions,
+/// e.g. the parts of the regions on both sides of the origin of a circular contig.
+pub(crate) fn segments_distance<C>(a: &[(C, C)], b: &[(C, C)]) -> DirectedDistance<C>
+where
+    C: Unit,
+{
+    a.iter()
+        .flat_map(|(a_start, a_end)| {
+            b.iter()
+                .map(move |(b_start, b_end)| distance(a_start, a_end, b_start, b_end))
+        })
+        .min_by_key(|d| (!d.is_overlapping(), d.abs()))
+        .expect("Region must consist of at least one segment")
+}
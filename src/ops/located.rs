@@ -1,24 +1,74 @@
+use crate::ops::func::{end_with_coordinate_system, start_with_coordinate_system};
+use crate::ops::Unit;
+use crate::{CoordinateSystem, CoordinateSystemMarker};
+
 pub trait Located<U> {
+    type System: CoordinateSystemMarker;
+
     fn start(&self) -> &U;
 
     fn end(&self) -> &U;
 
+    fn coordinate_system(&self) -> CoordinateSystem {
+        Self::System::COORDINATE_SYSTEM
+    }
+
     fn coordinates(&self) -> (&U, &U) {
         (self.start(), self.end())
     }
+
+    fn start_with_coordinate_system(&self, target: CoordinateSystem) -> U
+    where
+        U: Unit,
+    {
+        start_with_coordinate_system(*self.start(), self.coordinate_system(), target)
+    }
+
+    fn end_with_coordinate_system(&self, target: CoordinateSystem) -> U
+    where
+        U: Unit,
+    {
+        end_with_coordinate_system(*self.end(), self.coordinate_system(), target)
+    }
+
+    /// Get the length of the circular sequence the region is located on, or `None` if the
+    /// sequence is linear.
+    ///
+    /// A region ending before its start on a circular sequence wraps around the origin.
+    fn circular_length(&self) -> Option<U> {
+        None
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::ops::located::Located;
+    use crate::{CoordinateSystem, CoordinateSystemMarker, OneBased, ZeroBased};
     use rstest::rstest;
+    use std::marker::PhantomData;
 
-    struct TestRegion<C> {
+    struct TestRegion<C, S> {
         start: C,
         end: C,
+        system: PhantomData<S>,
     }
 
-    impl<C> Located<C> for TestRegion<C> {
+    impl<C, S> TestRegion<C, S> {
+        fn new(start: C, end: C) -> Self {
+            TestRegion {
+                start,
+                end,
+                system: PhantomData,
+            }
+        }
+    }
+
+    impl<C, S> Located<C> for TestRegion<C, S>
+    where
+        S: CoordinateSystemMarker,
+    {
+        type System = S;
+
         fn start(&self) -> &C {
             &self.start
         }
@@ -32,9 +82,35 @@ mod test {
     #[case(4, 20)]
     #[case(10, 20)]
     fn test_with_coordinate_system(#[case] start: u8, #[case] end: u8) {
-        let region = TestRegion { start, end };
+        let region: TestRegion<u8, ZeroBased> = TestRegion::new(start, end);
+        assert_eq!(region.coordinate_system(), CoordinateSystem::zero_based());
         assert_eq!(region.start, start);
         assert_eq!(region.end, end);
         assert_eq!(region.coordinates(), (&start, &end));
     }
+
+    #[rstest]
+    #[case(CoordinateSystem::LeftOpen, 10, 20)]
+    #[case(CoordinateSystem::FullyClosed, 11, 20)]
+    #[case(CoordinateSystem::FullyOpen, 10, 21)]
+    #[case(CoordinateSystem::RightOpen, 11, 21)]
+    fn test_coordinates_with_coordinate_system(
+        #[case] target: CoordinateSystem,
+        #[case] expected_start: u8,
+        #[case] expected_end: u8,
+    ) {
+        let zero_based: TestRegion<u8, ZeroBased> = TestRegion::new(10, 20);
+        assert_eq!(
+            zero_based.start_with_coordinate_system(target),
+            expected_start
+        );
+        assert_eq!(zero_based.end_with_coordinate_system(target), expected_end);
+
+        let one_based: TestRegion<u8, OneBased> = TestRegion::new(11, 20);
+        assert_eq!(
+            one_based.start_with_coordinate_system(target),
+            expected_start
+        );
+        assert_eq!(one_based.end_with_coordinate_system(target), expected_end);
+    }
 }
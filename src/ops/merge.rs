@@ -0,0 +1,313 @@
+use crate::genomic::{GenomicRegion, Strand, Stranded};
+use crate::ops::{overlaps, zero_based_segments_on_strand, Located, Spanning, Unit};
+use crate::{CoordinateSystem, CoordinateSystemMarker, Region};
+use std::collections::HashMap;
+
+/// A region produced by merging one or more input regions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merged<R> {
+    region: R,
+    indices: Vec<usize>,
+}
+
+impl<R> Merged<R> {
+    pub fn region(&self) -> &R {
+        &self.region
+    }
+
+    /// Get the indices of the input regions that contributed to the merged region.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Get the number of input regions that contributed to the merged region.
+    pub fn count(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn into_region(self) -> R {
+        self.region
+    }
+}
+
+/// Merge overlapping regions, like `bedtools merge`.
+///
+/// If `max_distance` is provided, the regions separated by at most `max_distance` bases
+/// are merged as well, e.g. `Some(0)` merges the book-ended regions.
+/// The merged regions are sorted by their coordinates.
+pub fn merge<C, T, I>(regions: I, max_distance: Option<C>) -> Vec<Merged<Region<C, T::System>>>
+where
+    C: Unit,
+    T: Located<C>,
+    I: IntoIterator<Item = T>,
+{
+    let cs = CoordinateSystem::zero_based();
+    let entries = regions
+        .into_iter()
+        .enumerate()
+        .map(|(i, r)| {
+            (
+                r.start_with_coordinate_system(cs),
+                r.end_with_coordinate_system(cs),
+                i,
+            )
+        })
+        .collect();
+    merge_coordinates(entries, max_distance)
+        .into_iter()
+        .map(|(start, end, indices)| Merged {
+            region: Region::from_zero_based(start, end),
+            indices,
+        })
+        .collect()
+}
+
+/// Merge overlapping genomic regions located on the same contig.
+///
+/// If `stranded` is `true`, only the regions on the same strand are merged and the merged
+/// regions keep the strand. Otherwise, the regions are merged regardless of the strand and
+/// the merged regions are reported on the forward strand.
+///
+/// The contigs are reported in the order of their first appearance in `regions`
+/// and the merged regions of each contig are sorted by their coordinates.
+/// The regions wrapping around the origin of a circular contig are merged with the regions
+/// on both sides of the origin.
+pub fn merge_genomic<'g, C, S, I>(
+    regions: I,
+    max_distance: Option<C>,
+    stranded: bool,
+) -> Vec<Merged<GenomicRegion<'g, C, S>>>
+where
+    C: Unit,
+    S: CoordinateSystemMarker,
+    I: IntoIterator<Item = GenomicRegion<'g, C, S>>,
+{
+    let mut groups = Vec::new();
+    let mut group_indices: HashMap<(&str, Option<Strand>), usize> = HashMap::new();
+    for (i, region) in regions.into_iter().enumerate() {
+        let strand = if stranded {
+            region.strand()
+        } else {
+            Strand::Forward
+        };
+        let contig = region.contig_ref();
+        let key = (contig.name(), if stranded { Some(strand) } else { None });
+        let group = *group_indices.entry(key).or_insert_with(|| {
+            groups.push((contig, strand, Vec::new()));
+            groups.len() - 1
+        });
+        for (start, end) in zero_based_segments_on_strand(&region, strand) {
+            groups[group].2.push((start, end, i));
+        }
+    }
+
+    groups
+        .into_iter()
+        .flat_map(|(contig, strand, entries)| {
+            let mut merged = merge_coordinates(entries, max_distance);
+            if contig.is_circular() {
+                join_across_origin(&mut merged, contig.span(), max_distance);
+            }
+            merged.into_iter().map(move |(start, end, indices)| Merged {
+                region: GenomicRegion::from_zero_based(contig, start, end, strand),
+                indices,
+            })
+        })
+        .collect()
+}
+
+/// Join the first and the last of the `merged` regions of a circular contig of the given
+/// `length` into a region wrapping around the origin if a region wrapping around the origin
+/// contributed to both or if they are mergeable across the origin.
+fn join_across_origin<C>(merged: &mut Vec<(C, C, Vec<usize>)>, length: C, max_distance: Option<C>)
+where
+    C: Unit,
+{
+    if merged.len() < 2 {
+        return;
+    }
+    let (first_start, _, first_indices) = &merged[0];
+    let (_, last_end, last_indices) = &merged[merged.len() - 1];
+    let wrapping = first_start.is_zero()
+        && *last_end == length
+        && first_indices.iter().any(|i| last_indices.contains(i));
+    let gap = *first_start + (length - *last_end);
+    let mergeable = match max_distance {
+        Some(distance) => gap <= distance,
+        None => false,
+    };
+    if wrapping || mergeable {
+        let (_, end, indices) = merged.remove(0);
+        if let Some((_, last_end, last_indices)) = merged.last_mut() {
+            *last_end = end;
+            last_indices.extend(indices);
+            last_indices.sort_unstable();
+            last_indices.dedup();
+        }
+    }
+}
+
+/// Merge the zero-based `entries` consisting of start, end and the index of the input region.
+fn merge_coordinates<C>(
+    mut entries: Vec<(C, C, usize)>,
+    max_distance: Option<C>,
+) -> Vec<(C, C, Vec<usize>)>
+where
+    C: Unit,
+{
+    entries.sort_by_key(|(start, end, i)| (*start, *end, *i));
+
+    let mut merged: Vec<(C, C, Vec<usize>)> = Vec::new();
+    for (start, end, i) in entries {
+        if let Some((current_start, current_end, indices)) = merged.last_mut() {
+            let mergeable = match max_distance {
+                Some(distance) => start <= current_end.saturating_add(distance),
+                None => overlaps(current_start, current_end, &start, &end),
+            };
+            if mergeable {
+                *current_end = end.max(*current_end);
+                indices.push(i);
+                continue;
+            }
+        }
+        merged.push((start, end, vec![i]));
+    }
+    for (_, _, indices) in merged.iter_mut() {
+        indices.sort_unstable();
+        indices.dedup();
+    }
+    merged
+}
+
+#[cfg(test)]
+mod test {
+    use super::{merge, merge_genomic};
+    use crate::genomic::{AssignedMoleculeType, Contig, GenomicRegion, SequenceRole, Strand};
+    use crate::ops::Located;
+    use crate::{OneBased, Region, ZeroBased};
+    use rstest::rstest;
+
+    fn regions() -> Vec<Region<u32, ZeroBased>> {
+        vec![
+            Region::new(50, 60).unwrap(),
+            Region::new(0, 10).unwrap(),
+            Region::new(5, 20).unwrap(),
+            Region::new(20, 30).unwrap(),
+            Region::new(32, 40).unwrap(),
+        ]
+    }
+
+    #[rstest]
+    #[case(None, vec![(0, 20, vec![1, 2]), (20, 30, vec![3]), (32, 40, vec![4]), (50, 60, vec![0])])]
+    #[case(Some(0), vec![(0, 30, vec![1, 2, 3]), (32, 40, vec![4]), (50, 60, vec![0])])]
+    #[case(Some(2), vec![(0, 40, vec![1, 2, 3, 4]), (50, 60, vec![0])])]
+    fn test_merge(
+        #[case] max_distance: Option<u32>,
+        #[case] expected: Vec<(u32, u32, Vec<usize>)>,
+    ) {
+        let merged = merge(regions(), max_distance);
+        let actual: Vec<_> = merged
+            .iter()
+            .map(|m| (*m.region().start(), *m.region().end(), m.indices().to_vec()))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    fn test_merge_one_based() {
+        let regions: Vec<Region<u32, OneBased>> =
+            vec![Region::new(1, 10).unwrap(), Region::new(10, 20).unwrap()];
+        let merged = merge(regions, None);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].region(), &Region::new(1, 20).unwrap());
+        assert_eq!(merged[0].count(), 2);
+    }
+
+    fn contig(name: &str) -> Contig<u32> {
+        Contig::new(
+            name.to_string(),
+            SequenceRole::AssembledMolecule,
+            name.to_string(),
+            AssignedMoleculeType::Chromosome,
+            100,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_merge_genomic() {
+        let one = contig("1");
+        let two = contig("2");
+        let regions: Vec<GenomicRegion<u32, ZeroBased>> = vec![
+            GenomicRegion::new(&one, 10, 20, Strand::Forward).unwrap(),
+            GenomicRegion::new(&two, 10, 20, Strand::Forward).unwrap(),
+            // (15, 25] on the forward strand.
+            GenomicRegion::new(&one, 75, 85, Strand::Reverse).unwrap(),
+            GenomicRegion::new(&one, 40, 50, Strand::Forward).unwrap(),
+        ];
+
+        let merged = merge_genomic(regions.clone(), None, false);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(
+            merged[0].region(),
+            &GenomicRegion::new(&one, 10, 25, Strand::Forward).unwrap()
+        );
+        assert_eq!(merged[0].indices(), &[0, 2]);
+        assert_eq!(
+            merged[1].region(),
+            &GenomicRegion::new(&one, 40, 50, Strand::Forward).unwrap()
+        );
+        assert_eq!(
+            merged[2].region(),
+            &GenomicRegion::new(&two, 10, 20, Strand::Forward).unwrap()
+        );
+
+        let merged = merge_genomic(regions, None, true);
+        assert_eq!(merged.len(), 4);
+        assert_eq!(
+            merged[3].region(),
+            &GenomicRegion::new(&one, 75, 85, Strand::Reverse).unwrap()
+        );
+        assert_eq!(merged[3].indices(), &[2]);
+    }
+
+    #[rstest]
+    fn test_merge_genomic_wrapping() {
+        let mt = Contig::new(
+            "MT".to_string(),
+            SequenceRole::AssembledMolecule,
+            "MT".to_string(),
+            AssignedMoleculeType::Mitochondrion,
+            100,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+        .unwrap();
+        let region = |start, end| -> GenomicRegion<u32, ZeroBased> {
+            GenomicRegion::new(&mt, start, end, Strand::Forward).unwrap()
+        };
+
+        // (90, 100] and (0, 10] on the forward strand.
+        let merged = merge_genomic(vec![region(5, 30), region(90, 10)], None, false);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].region(), &region(90, 30));
+        assert_eq!(merged[0].indices(), &[0, 1]);
+
+        let merged = merge_genomic(vec![region(90, 10), region(40, 50)], None, false);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].region(), &region(40, 50));
+        assert_eq!(merged[1].region(), &region(90, 10));
+        assert_eq!(merged[1].indices(), &[0]);
+
+        let regions = vec![region(0, 10), region(40, 50), region(95, 100)];
+        assert_eq!(merge_genomic(regions.clone(), None, false).len(), 3);
+        let merged = merge_genomic(regions, Some(0), false);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[1].region(), &region(95, 10));
+        assert_eq!(merged[1].indices(), &[0, 2]);
+    }
+}
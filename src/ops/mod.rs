@@ -1,19 +1,29 @@
 mod contains;
+mod distance;
+pub(crate) mod func;
 mod located;
+mod merge;
 mod overlaps;
-// mod distance;
-mod func;
+mod set_operations;
 mod spanning;
 mod transposable;
 mod unit;
 
 pub use contains::Contains;
 pub use contains::GenomicallyContains;
+pub use distance::DirectedDistance;
+pub use distance::Distance;
+pub use distance::GenomicDistance;
 pub use func::contains;
+pub use func::distance;
 pub use func::overlaps;
 pub use located::Located;
+pub use merge::{merge, merge_genomic, Merged};
 pub use overlaps::GenomicallyOverlaps;
 pub use overlaps::Overlaps;
+pub use set_operations::GenomicSetOperations;
+pub use set_operations::SetOperations;
 pub use spanning::Spanning;
 pub use transposable::Transposable;
+pub(crate) use transposable::{zero_based_on_strand, zero_based_segments_on_strand};
 pub use unit::Unit;
@@ -1,5 +1,7 @@
-use crate::genomic::{Contiged, Stranded};
-use crate::ops::{overlaps, Located, Transposable, Unit};
+use crate::genomic::{Contiged, StrandMode, Stranded};
+use crate::ops::transposable::zero_based_segments_on_strand;
+use crate::ops::{overlaps, Located, Unit};
+use crate::CoordinateSystem;
 
 pub trait Overlaps<C, O = Self>
 where
@@ -8,21 +10,33 @@ where
     fn overlaps(&self, other: &O) -> bool;
 }
 
-impl<C, T> Overlaps<C> for T
+impl<C, T, O> Overlaps<C, O> for T
 where
     C: Unit,
     T: Located<C>,
+    O: Located<C, System = T::System>,
 {
-    fn overlaps(&self, other: &Self) -> bool {
-        overlaps(self.start(), self.end(), other.start(), other.end())
+    fn overlaps(&self, other: &O) -> bool {
+        let cs = CoordinateSystem::zero_based();
+        overlaps(
+            &self.start_with_coordinate_system(cs),
+            &self.end_with_coordinate_system(cs),
+            &other.start_with_coordinate_system(cs),
+            &other.end_with_coordinate_system(cs),
+        )
     }
 }
 
+/// The genomic regions are compared on the strand of `self`, regardless of the strand
+/// of `other`, unless [`StrandMode::Strict`] is used. The regions wrapping around the origin
+/// of a circular contig are compared piecewise.
 pub trait GenomicallyOverlaps<C, O = Self>
 where
     O: ?Sized,
 {
     fn overlaps(&self, other: &O) -> bool;
+
+    fn overlaps_with_mode(&self, other: &O, mode: StrandMode) -> bool;
 }
 
 impl<C: Unit, T> GenomicallyOverlaps<C> for T
@@ -31,17 +45,21 @@ where
     T: Located<C> + Stranded + Contiged<C>,
 {
     fn overlaps(&self, other: &Self) -> bool {
-        if self.contig().ne(other.contig()) {
-            return false;
-        }
+        self.overlaps_with_mode(other, StrandMode::Ignore)
+    }
 
-        if self.strand().eq(&other.strand()) {
-            return overlaps(self.start(), self.end(), other.start(), other.end());
+    fn overlaps_with_mode(&self, other: &Self, mode: StrandMode) -> bool {
+        if self.contig().ne(other.contig()) || !mode.matches(self.strand(), other.strand()) {
+            return false;
         }
 
-        let other_start = other.start_on_strand(self.strand());
-        let other_end = other.end_on_strand(self.strand());
-        overlaps(self.start(), self.end(), &other_start, &other_end)
+        let segments = zero_based_segments_on_strand(self, self.strand());
+        let other_segments = zero_based_segments_on_strand(other, self.strand());
+        segments.iter().any(|(start, end)| {
+            other_segments
+                .iter()
+                .any(|(other_start, other_end)| overlaps(start, end, other_start, other_end))
+        })
     }
 }
 
@@ -49,6 +67,7 @@ where
 mod test {
     use crate::ops::located::Located;
     use crate::ops::Overlaps;
+    use crate::ZeroBased;
     use rstest::rstest;
 
     pub struct TestRegion {
@@ -57,6 +76,8 @@ mod test {
     }
 
     impl Located<u8> for TestRegion {
+        type System = ZeroBased;
+
         fn start(&self) -> &u8 {
             &self.start
         }
@@ -0,0 +1,209 @@
+use crate::ops::func::{difference, hull, intersection, overlap_length, union};
+use crate::ops::{Located, Unit};
+use crate::{CoordinateSystem, Region};
+
+pub trait SetOperations<C, O = Self>
+where
+    O: ?Sized,
+    Self: Located<C>,
+{
+    /// Get the number of bases shared by the regions.
+    fn overlap_length(&self, other: &O) -> C;
+
+    fn intersection(&self, other: &O) -> Option<Region<C, Self::System>>;
+
+    /// Get the union of the regions, or `None` if the regions neither overlap nor abut each other.
+    fn union(&self, other: &O) -> Option<Region<C, Self::System>>;
+
+    /// Get the smallest region that contains both regions.
+    fn hull(&self, other: &O) -> Region<C, Self::System>;
+
+    /// Get the parts of `self` that are not covered by `other`.
+    fn difference(&self, other: &O) -> Vec<Region<C, Self::System>>;
+}
+
+impl<C, T, O> SetOperations<C, O> for T
+where
+    C: Unit,
+    T: Located<C>,
+    O: Located<C, System = T::System>,
+{
+    fn overlap_length(&self, other: &O) -> C {
+        let (a_start, a_end) = zero_based(self);
+        let (b_start, b_end) = zero_based(other);
+        overlap_length(&a_start, &a_end, &b_start, &b_end)
+    }
+
+    fn intersection(&self, other: &O) -> Option<Region<C, T::System>> {
+        let (a_start, a_end) = zero_based(self);
+        let (b_start, b_end) = zero_based(other);
+        intersection(&a_start, &a_end, &b_start, &b_end).map(|(s, e)| Region::from_zero_based(s, e))
+    }
+
+    fn union(&self, other: &O) -> Option<Region<C, T::System>> {
+        let (a_start, a_end) = zero_based(self);
+        let (b_start, b_end) = zero_based(other);
+        union(&a_start, &a_end, &b_start, &b_end).map(|(s, e)| Region::from_zero_based(s, e))
+    }
+
+    fn hull(&self, other: &O) -> Region<C, T::System> {
+        let (a_start, a_end) = zero_based(self);
+        let (b_start, b_end) = zero_based(other);
+        let (start, end) = hull(&a_start, &a_end, &b_start, &b_end);
+        Region::from_zero_based(start, end)
+    }
+
+    fn difference(&self, other: &O) -> Vec<Region<C, T::System>> {
+        let (a_start, a_end) = zero_based(self);
+        let (b_start, b_end) = zero_based(other);
+        difference(&a_start, &a_end, &b_start, &b_end)
+            .into_iter()
+            .map(|(s, e)| Region::from_zero_based(s, e))
+            .collect()
+    }
+}
+
+fn zero_based<C, T>(region: &T) -> (C, C)
+where
+    C: Unit,
+    T: Located<C> + ?Sized,
+{
+    let cs = CoordinateSystem::zero_based();
+    (
+        region.start_with_coordinate_system(cs),
+        region.end_with_coordinate_system(cs),
+    )
+}
+
+/// Strand and contig-aware set operations.
+///
+/// The `other` region is transposed to the strand of `self` and the results are reported on the
+/// strand of `self`.
+pub trait GenomicSetOperations<C, O = Self>
+where
+    O: ?Sized,
+{
+    type Output;
+
+    /// Get the number of bases shared by the regions, or zero if the regions are on different contigs.
+    fn overlap_length(&self, other: &O) -> C;
+
+    /// Get the intersection of the regions, or `None` if the regions are on different contigs,
+    /// do not overlap, or overlap at both ends of a region wrapping around the origin.
+    fn intersection(&self, other: &O) -> Option<Self::Output>;
+
+    /// Get the union of the regions, or `None` if the regions are on different contigs
+    /// or neither overlap nor abut each other.
+    fn union(&self, other: &O) -> Option<Self::Output>;
+
+    /// Get the smallest region that contains both regions, or `None` if the regions are on different contigs.
+    fn hull(&self, other: &O) -> Option<Self::Output>;
+
+    /// Get the parts of `self` that are not covered by `other`.
+    fn difference(&self, other: &O) -> Vec<Self::Output>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::SetOperations;
+    use crate::ops::Located;
+    use crate::{OneBased, Region, ZeroBased};
+    use rstest::rstest;
+
+    struct TestRegion {
+        start: u8,
+        end: u8,
+    }
+
+    impl Located<u8> for TestRegion {
+        type System = ZeroBased;
+
+        fn start(&self) -> &u8 {
+            &self.start
+        }
+
+        fn end(&self) -> &u8 {
+            &self.end
+        }
+    }
+
+    fn region(start: u8, end: u8) -> Region<u8, ZeroBased> {
+        Region::new(start, end).unwrap()
+    }
+
+    #[rstest]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 5, end: 20 }, 5)]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 10, end: 20 }, 0)]
+    #[case(TestRegion { start: 0, end: 30 }, TestRegion { start: 10, end: 20 }, 10)]
+    fn test_overlap_length(
+        #[case] region_1: TestRegion,
+        #[case] region_2: TestRegion,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(region_1.overlap_length(&region_2), expected);
+        assert_eq!(region_2.overlap_length(&region_1), expected);
+    }
+
+    #[rstest]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 5, end: 20 }, Some(region(5, 10)))]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 10, end: 20 }, None)]
+    #[case(TestRegion { start: 10, end: 10 }, TestRegion { start: 10, end: 10 }, Some(region(10, 10)))]
+    fn test_intersection(
+        #[case] region_1: TestRegion,
+        #[case] region_2: TestRegion,
+        #[case] expected: Option<Region<u8, ZeroBased>>,
+    ) {
+        assert_eq!(region_1.intersection(&region_2), expected);
+    }
+
+    #[rstest]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 5, end: 20 }, Some(region(0, 20)))]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 10, end: 20 }, Some(region(0, 20)))]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 11, end: 20 }, None)]
+    fn test_union(
+        #[case] region_1: TestRegion,
+        #[case] region_2: TestRegion,
+        #[case] expected: Option<Region<u8, ZeroBased>>,
+    ) {
+        assert_eq!(region_1.union(&region_2), expected);
+        assert_eq!(region_2.union(&region_1), expected);
+    }
+
+    #[rstest]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 15, end: 20 }, region(0, 20))]
+    #[case(TestRegion { start: 5, end: 10 }, TestRegion { start: 0, end: 7 }, region(0, 10))]
+    fn test_hull(
+        #[case] region_1: TestRegion,
+        #[case] region_2: TestRegion,
+        #[case] expected: Region<u8, ZeroBased>,
+    ) {
+        assert_eq!(region_1.hull(&region_2), expected);
+    }
+
+    #[rstest]
+    #[case(TestRegion { start: 0, end: 30 }, TestRegion { start: 10, end: 20 }, vec![region(0, 10), region(20, 30)])]
+    #[case(TestRegion { start: 0, end: 15 }, TestRegion { start: 10, end: 20 }, vec![region(0, 10)])]
+    #[case(TestRegion { start: 15, end: 30 }, TestRegion { start: 10, end: 20 }, vec![region(20, 30)])]
+    #[case(TestRegion { start: 12, end: 18 }, TestRegion { start: 10, end: 20 }, vec![])]
+    #[case(TestRegion { start: 0, end: 10 }, TestRegion { start: 10, end: 20 }, vec![region(0, 10)])]
+    fn test_difference(
+        #[case] region_1: TestRegion,
+        #[case] region_2: TestRegion,
+        #[case] expected: Vec<Region<u8, ZeroBased>>,
+    ) {
+        assert_eq!(region_1.difference(&region_2), expected);
+    }
+
+    #[rstest]
+    fn test_one_based() {
+        let a: Region<u8, OneBased> = Region::new(1, 10).unwrap();
+        let b: Region<u8, OneBased> = Region::new(10, 20).unwrap();
+        assert_eq!(a.overlap_length(&b), 1);
+        assert_eq!(a.intersection(&b), Some(Region::new(10, 10).unwrap()));
+        assert_eq!(a.hull(&b), Region::new(1, 20).unwrap());
+        assert_eq!(
+            a.difference(&b),
+            vec![Region::<u8, OneBased>::new(1, 9).unwrap()]
+        );
+    }
+}
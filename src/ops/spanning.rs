@@ -1,5 +1,10 @@
 use super::{Located, Unit};
+use crate::CoordinateSystem;
 
+/// The number of bases spanned by a region.
+///
+/// A region ending before its start spans no bases, unless it wraps around the origin
+/// of a circular sequence (see [`Located::circular_length`]).
 pub trait Spanning<C> {
     fn span(&self) -> C;
 
@@ -11,7 +16,15 @@ where
     T: Located<C>,
 {
     fn span(&self) -> C {
-        *self.end() - *self.start()
+        let cs = CoordinateSystem::zero_based();
+        let start = self.start_with_coordinate_system(cs);
+        let end = self.end_with_coordinate_system(cs);
+        if end < start {
+            self.circular_length()
+                .map_or(C::zero(), |length| length.saturating_sub(start) + end)
+        } else {
+            end - start
+        }
     }
 
     fn is_empty(&self) -> bool {
@@ -23,6 +36,7 @@ where
 mod test {
     use crate::ops::located::Located;
     use crate::ops::spanning::Spanning;
+    use crate::ZeroBased;
     use rstest::rstest;
 
     struct TestRegion {
@@ -31,6 +45,8 @@ mod test {
     }
 
     impl Located<u8> for TestRegion {
+        type System = ZeroBased;
+
         fn start(&self) -> &u8 {
             &self.start
         }
@@ -43,10 +59,13 @@ mod test {
     #[rstest]
     #[case(4, 20, 16)]
     #[case(10, 20, 10)]
+    #[case(10, 10, 0)]
+    #[case(20, 10, 0)]
     fn test_with_coordinate_system(#[case] start: u8, #[case] end: u8, #[case] expected: u8) {
         let region = TestRegion { start, end };
         assert_eq!(region.start, start);
         assert_eq!(region.end, end);
         assert_eq!(region.span(), expected);
+        assert_eq!(region.is_empty(), expected == 0);
     }
 }
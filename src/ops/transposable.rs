@@ -1,6 +1,13 @@
 use crate::genomic::{Contiged, Strand, Stranded};
-use crate::ops::{Located, Unit};
+use crate::ops::func::{end_with_coordinate_system, start_with_coordinate_system};
+use crate::ops::{Located, Spanning, Unit};
+use crate::CoordinateSystem;
 
+/// Coordinates of a region on either strand of its contig.
+///
+/// The regions with an unknown strand are located on the forward strand, hence transposing
+/// between the forward and unknown strands does not change the coordinates. A region wrapping
+/// around the origin of a circular contig wraps around the origin on both strands.
 pub trait Transposable<C> {
     fn start_on_strand(&self, strand: Strand) -> C;
 
@@ -13,41 +20,98 @@ where
     T: Contiged<C> + Located<C> + Stranded,
 {
     fn start_on_strand(&self, strand: Strand) -> C {
-        match self.strand().eq(&strand) {
+        match self.strand().is_reverse() == strand.is_reverse() {
             true => *self.start(),
-            false => *self.contig().end() - *self.end(),
+            false => {
+                let zero_based = CoordinateSystem::zero_based();
+                let start = self
+                    .contig()
+                    .span()
+                    .saturating_sub(self.end_with_coordinate_system(zero_based));
+                start_with_coordinate_system(start, zero_based, self.coordinate_system())
+            }
         }
     }
 
     fn end_on_strand(&self, strand: Strand) -> C {
-        match self.strand().eq(&strand) {
+        match self.strand().is_reverse() == strand.is_reverse() {
             true => *self.end(),
-            false => *self.contig().end() - *self.start(),
+            false => {
+                let zero_based = CoordinateSystem::zero_based();
+                let end = self
+                    .contig()
+                    .span()
+                    .saturating_sub(self.start_with_coordinate_system(zero_based));
+                end_with_coordinate_system(end, zero_based, self.coordinate_system())
+            }
         }
     }
 }
 
+/// Get the zero-based coordinates of the `region` on the `strand`.
+pub(crate) fn zero_based_on_strand<C, T>(region: &T, strand: Strand) -> (C, C)
+where
+    C: Unit,
+    T: Located<C> + Transposable<C>,
+{
+    let cs = CoordinateSystem::zero_based();
+    (
+        start_with_coordinate_system(
+            region.start_on_strand(strand),
+            region.coordinate_system(),
+            cs,
+        ),
+        end_with_coordinate_system(region.end_on_strand(strand), region.coordinate_system(), cs),
+    )
+}
+
+/// Get the zero-based coordinates of the `region` on the `strand`, split at the contig origin
+/// if the region wraps around it.
+pub(crate) fn zero_based_segments_on_strand<C, T>(region: &T, strand: Strand) -> Vec<(C, C)>
+where
+    C: Unit,
+    T: Contiged<C> + Located<C> + Transposable<C>,
+{
+    let (start, end) = zero_based_on_strand(region, strand);
+    if start <= end {
+        return vec![(start, end)];
+    }
+    let length = region.contig().span();
+    [(start, length), (C::zero(), end)]
+        .into_iter()
+        .filter(|(start, end)| start < end)
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::genomic::{AssignedMoleculeType, Contig, SequenceRole};
+    use crate::{CoordinateSystemMarker, OneBased, ZeroBased};
     use rstest::rstest;
+    use std::marker::PhantomData;
 
     #[derive(PartialEq, Eq)]
-    struct TestRegion {
+    struct TestRegion<S> {
         strand: Strand,
         start: u8,
         end: u8,
+        system: PhantomData<S>,
         contig: Contig<u8>,
     }
 
-    impl Stranded for TestRegion {
+    impl<S> Stranded for TestRegion<S> {
         fn strand(&self) -> Strand {
             self.strand
         }
     }
 
-    impl Located<u8> for TestRegion {
+    impl<S> Located<u8> for TestRegion<S>
+    where
+        S: CoordinateSystemMarker,
+    {
+        type System = S;
+
         fn start(&self) -> &u8 {
             &self.start
         }
@@ -57,7 +121,7 @@ mod test {
         }
     }
 
-    impl Contiged<u8> for TestRegion {
+    impl<S> Contiged<u8> for TestRegion<S> {
         type Contig = Contig<u8>;
 
         fn contig(&self) -> &Self::Contig {
@@ -80,10 +144,11 @@ mod test {
     #[rstest]
     fn test_start_on_strand() {
         let contig = get_test_contig();
-        let test_region = TestRegion {
+        let test_region: TestRegion<ZeroBased> = TestRegion {
             strand: Strand::Reverse,
             start: 32,
             end: 200,
+            system: PhantomData,
             contig,
         };
         assert_eq!(
@@ -96,10 +161,11 @@ mod test {
     #[rstest]
     fn test_end_on_strand() {
         let contig = get_test_contig();
-        let test_region = TestRegion {
+        let test_region: TestRegion<ZeroBased> = TestRegion {
             strand: Strand::Reverse,
             start: 32,
             end: 200,
+            system: PhantomData,
             contig,
         };
         assert_eq!(
@@ -108,4 +174,54 @@ mod test {
         );
         assert_eq!(test_region.end_on_strand(Strand::Forward), 217);
     }
+
+    #[rstest]
+    fn test_on_strand_one_based() {
+        let contig = get_test_contig();
+        let test_region: TestRegion<OneBased> = TestRegion {
+            strand: Strand::Reverse,
+            start: 33,
+            end: 200,
+            system: PhantomData,
+            contig,
+        };
+        assert_eq!(test_region.start_on_strand(Strand::Forward), 50);
+        assert_eq!(test_region.end_on_strand(Strand::Forward), 217);
+    }
+
+    #[rstest]
+    fn test_on_strand_out_of_contig() {
+        let contig = get_test_contig();
+        let test_region: TestRegion<ZeroBased> = TestRegion {
+            strand: Strand::Reverse,
+            start: 200,
+            end: 255,
+            system: PhantomData,
+            contig,
+        };
+        assert_eq!(test_region.start_on_strand(Strand::Forward), 0);
+        assert_eq!(test_region.end_on_strand(Strand::Forward), 49);
+    }
+
+    #[rstest]
+    fn test_wrapping_on_strand() {
+        let contig = get_test_contig();
+        let test_region: TestRegion<ZeroBased> = TestRegion {
+            strand: Strand::Forward,
+            start: 240,
+            end: 10,
+            system: PhantomData,
+            contig,
+        };
+        assert_eq!(test_region.start_on_strand(Strand::Reverse), 239);
+        assert_eq!(test_region.end_on_strand(Strand::Reverse), 9);
+        assert_eq!(
+            zero_based_segments_on_strand(&test_region, Strand::Forward),
+            vec![(240, 249), (0, 10)]
+        );
+        assert_eq!(
+            zero_based_segments_on_strand(&test_region, Strand::Reverse),
+            vec![(239, 249), (0, 9)]
+        );
+    }
 }
@@ -1,11 +1,25 @@
-use std::ops::Sub;
+use std::ops::{Add, Sub};
 
-pub trait Unit: Sub<Output = Self> + Ord + Copy {
+pub trait Unit: Add<Output = Self> + Sub<Output = Self> + Ord + Copy {
     fn is_zero(&self) -> bool;
 
     fn zero() -> Self;
 
     fn one() -> Self;
+
+    fn checked_add(&self, other: Self) -> Option<Self>;
+
+    fn checked_sub(&self, other: Self) -> Option<Self>;
+
+    /// Convert the value into `u64`, or `None` if the value does not fit.
+    fn to_u64(&self) -> Option<u64>;
+
+    /// Convert `u64` into the unit, or `None` if the value does not fit.
+    fn from_u64(value: u64) -> Option<Self>;
+
+    fn saturating_add(&self, other: Self) -> Self;
+
+    fn saturating_sub(&self, other: Self) -> Self;
 }
 
 macro_rules! impl_unit {
@@ -22,6 +36,30 @@ macro_rules! impl_unit {
             fn one() -> Self {
                 1
             }
+
+            fn checked_add(&self, other: Self) -> Option<Self> {
+                <$TYPE>::checked_add(*self, other)
+            }
+
+            fn checked_sub(&self, other: Self) -> Option<Self> {
+                <$TYPE>::checked_sub(*self, other)
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                u64::try_from(*self).ok()
+            }
+
+            fn from_u64(value: u64) -> Option<Self> {
+                <$TYPE>::try_from(value).ok()
+            }
+
+            fn saturating_add(&self, other: Self) -> Self {
+                <$TYPE>::saturating_add(*self, other)
+            }
+
+            fn saturating_sub(&self, other: Self) -> Self {
+                <$TYPE>::saturating_sub(*self, other)
+            }
         }
     };
 }
@@ -32,6 +70,12 @@ impl_unit!(u32);
 impl_unit!(u64);
 impl_unit!(u128);
 impl_unit!(usize);
+impl_unit!(i8);
+impl_unit!(i16);
+impl_unit!(i32);
+impl_unit!(i64);
+impl_unit!(i128);
+impl_unit!(isize);
 
 #[cfg(test)]
 mod test {
@@ -42,9 +86,30 @@ mod test {
     #[case(4, 0)]
     #[case(10, 0)]
     fn test_with_coordinate_system(#[case] start: u8, #[case] end: u8) {
-        assert_eq!(start.is_zero(), false);
-        assert_eq!(end.is_zero(), true);
+        assert!(!start.is_zero());
+        assert!(end.is_zero());
         assert_eq!(u8::zero(), 0);
         assert_eq!(u8::one(), 1);
     }
+
+    #[rstest]
+    fn test_checked() {
+        assert_eq!(Unit::checked_sub(&0u32, 1), None);
+        assert_eq!(Unit::checked_sub(&0i32, 1), Some(-1));
+        assert_eq!(Unit::checked_add(&u8::MAX, 1), None);
+        assert_eq!(Unit::saturating_sub(&0u32, 1), 0);
+        assert_eq!(Unit::saturating_add(&i8::MAX, 1), i8::MAX);
+        assert_eq!(Unit::saturating_sub(&i8::MIN, 1), i8::MIN);
+        assert_eq!(Unit::saturating_sub(&i8::MAX, -1), i8::MAX);
+    }
+
+    #[rstest]
+    fn test_u64_conversion() {
+        assert_eq!(300u32.to_u64(), Some(300));
+        assert_eq!((-45i32).to_u64(), None);
+        assert_eq!(u128::MAX.to_u64(), None);
+        assert_eq!(u8::from_u64(300), None);
+        assert_eq!(i64::from_u64(300), Some(300));
+        assert_eq!(i64::from_u64(u64::MAX), None);
+    }
 }
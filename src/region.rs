@@ -1,25 +1,121 @@
-use crate::ops::Located;
+use crate::ops::func::{
+    checked_end_with_coordinate_system, checked_start_with_coordinate_system,
+    end_with_coordinate_system, start_with_coordinate_system,
+};
+use crate::ops::{Located, Unit};
+use crate::{CoordinateSystem, CoordinateSystemMarker, OneBased, SvartError, ZeroBased};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
 
+/// A region with coordinates in the coordinate system `S`.
+///
+/// Regions in different coordinate systems cannot be compared without an explicit conversion:
+///
+/// ```compile_fail
+/// use rsvart::ops::Overlaps;
+/// use rsvart::{OneBased, Region, ZeroBased};
+///
+/// let bed: Region<u32, ZeroBased> = Region::new(99, 100).unwrap();
+/// let vcf: Region<u32, OneBased> = Region::new(100, 100).unwrap();
+/// bed.overlaps(&vcf);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Region<C> {
+pub struct Region<C, S> {
     start: C,
     end: C,
+    system: PhantomData<S>,
 }
 
-impl<C> Region<C>
+impl<C, S> Region<C, S>
 where
-    C: PartialOrd,
+    C: Unit,
+    S: CoordinateSystemMarker,
 {
+    /// Create a region, or `None` if the region starts after it ends or its coordinates
+    /// are not representable by `C` as zero-based coordinates, e.g. a one-based start of `0u32`.
     pub fn new(start: C, end: C) -> Option<Self> {
-        if start > end {
+        let cs = CoordinateSystem::zero_based();
+        let zero_based_start =
+            checked_start_with_coordinate_system(start, S::COORDINATE_SYSTEM, cs)?;
+        let zero_based_end = checked_end_with_coordinate_system(end, S::COORDINATE_SYSTEM, cs)?;
+        if zero_based_start > zero_based_end {
             None
         } else {
-            Some(Self { start, end })
+            Some(Self {
+                start,
+                end,
+                system: PhantomData,
+            })
         }
     }
+
+    /// Create a region like [`Region::new`], reporting the coordinates if the region starts
+    /// after it ends or before the first position of the coordinate system `S`.
+    pub fn try_new(start: C, end: C) -> Result<Self, SvartError>
+    where
+        C: Display,
+    {
+        let cs = CoordinateSystem::zero_based();
+        let zero_based_start =
+            checked_start_with_coordinate_system(start, S::COORDINATE_SYSTEM, cs);
+        let zero_based_end = checked_end_with_coordinate_system(end, S::COORDINATE_SYSTEM, cs);
+        match zero_based_start.zip(zero_based_end) {
+            None => Err(SvartError::CoordinateOutOfBoundsError {
+                start: start.to_string(),
+                end: end.to_string(),
+            }),
+            Some((zero_based_start, zero_based_end)) if zero_based_start > zero_based_end => {
+                Err(SvartError::InvalidRegionError {
+                    start: start.to_string(),
+                    end: end.to_string(),
+                })
+            }
+            Some(_) => Ok(Self {
+                start,
+                end,
+                system: PhantomData,
+            }),
+        }
+    }
+
+    /// Create a region from valid zero-based coordinates.
+    pub(crate) fn from_zero_based(start: C, end: C) -> Self {
+        let cs = CoordinateSystem::zero_based();
+        Self {
+            start: start_with_coordinate_system(start, cs, S::COORDINATE_SYSTEM),
+            end: end_with_coordinate_system(end, cs, S::COORDINATE_SYSTEM),
+            system: PhantomData,
+        }
+    }
+
+    /// Convert the region into the coordinate system `T`.
+    pub fn into_coordinate_system<T>(self) -> Region<C, T>
+    where
+        T: CoordinateSystemMarker,
+    {
+        Region {
+            start: self.start_with_coordinate_system(T::COORDINATE_SYSTEM),
+            end: self.end_with_coordinate_system(T::COORDINATE_SYSTEM),
+            system: PhantomData,
+        }
+    }
+
+    pub fn into_zero_based(self) -> Region<C, ZeroBased> {
+        self.into_coordinate_system()
+    }
+
+    pub fn into_one_based(self) -> Region<C, OneBased> {
+        self.into_coordinate_system()
+    }
 }
 
-impl<C> Located<C> for Region<C> {
+impl<C, S> Located<C> for Region<C, S>
+where
+    S: CoordinateSystemMarker,
+{
+    type System = S;
+
     fn start(&self) -> &C {
         &self.start
     }
@@ -29,25 +125,209 @@ impl<C> Located<C> for Region<C> {
     }
 }
 
+impl<C, S> FromStr for Region<C, S>
+where
+    C: Unit + FromStr + Display,
+    S: CoordinateSystemMarker,
+{
+    type Err = SvartError;
+
+    /// Parse a region formatted as `start-end` in the coordinate system `S`,
+    /// e.g. `1,000-2,000`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = parse_range(s, '-', 1)?;
+        Region::try_new(start, end)
+    }
+}
+
+impl<C, S> Display for Region<C, S>
+where
+    C: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Parse a coordinate starting at the one-based `column` of the input, ignoring thousands separators.
+pub(crate) fn parse_coordinate<C>(value: &str, column: usize) -> Result<C, SvartError>
+where
+    C: FromStr,
+{
+    let column = column + leading_whitespace(value);
+    let token = value.trim();
+    token.replace(',', "").parse().map_err(|_| {
+        SvartError::parse_error(
+            format!("Invalid coordinate '{}'", token),
+            Some(token),
+            Some(column),
+        )
+    })
+}
+
+/// Parse the start and end coordinates delimited by the `separator`, starting at the one-based
+/// `column` of the input.
+pub(crate) fn parse_range<C>(
+    value: &str,
+    separator: char,
+    column: usize,
+) -> Result<(C, C), SvartError>
+where
+    C: FromStr,
+{
+    let column = column + leading_whitespace(value);
+    let value = value.trim();
+    // Skip the first character to allow a negative start coordinate.
+    let split = value
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == separator)
+        .map(|(i, _)| i);
+    match split {
+        Some(i) => Ok((
+            parse_coordinate(&value[..i], column)?,
+            parse_coordinate(&value[i + 1..], column + i + 1)?,
+        )),
+        None => Err(SvartError::parse_error(
+            format!("Expected 'start{}end' but got '{}'", separator, value),
+            Some(value),
+            Some(column),
+        )),
+    }
+}
+
+fn leading_whitespace(value: &str) -> usize {
+    value.len() - value.trim_start().len()
+}
+
 #[cfg(test)]
 mod test {
     use crate::ops::{Contains, Located, Overlaps, Spanning};
-    use crate::Region;
+    use crate::{CoordinateSystem, ErrorKind, OneBased, Region, SvartError, ZeroBased};
     use rstest::rstest;
 
     #[rstest]
     #[case(1, 5)]
     fn test_region_methods(#[case] start: u8, #[case] end: u8) {
-        let region = Region::new(start, end).unwrap();
+        let region: Region<u8, ZeroBased> = Region::new(start, end).unwrap();
         let region_other = Region::new(2, 6).unwrap();
         assert_eq!(*region.start(), 1);
         assert_eq!(*region.end(), 5);
         let (start_coor, end_coor) = region.coordinates();
         assert_eq!(*start_coor, 1);
         assert_eq!(*end_coor, 5);
-        assert_eq!(region.contains(&region_other), false);
-        assert_eq!(region.overlaps(&region_other), true);
+        assert!(!region.contains(&region_other));
+        assert!(region.overlaps(&region_other));
         assert_eq!(region.span(), 4);
-        assert_eq!(region.is_empty(), false);
+        assert!(!region.is_empty());
+    }
+
+    #[rstest]
+    #[case(5, 4, false)]
+    #[case(5, 5, true)]
+    fn test_new_zero_based(#[case] start: u8, #[case] end: u8, #[case] expected: bool) {
+        assert_eq!(Region::<u8, ZeroBased>::new(start, end).is_some(), expected);
+    }
+
+    #[rstest]
+    #[case(5, 4, true)]
+    #[case(6, 4, false)]
+    fn test_new_one_based(#[case] start: u8, #[case] end: u8, #[case] expected: bool) {
+        assert_eq!(Region::<u8, OneBased>::new(start, end).is_some(), expected);
+    }
+
+    #[rstest]
+    fn test_try_new() {
+        assert!(Region::<u8, ZeroBased>::try_new(5, 5).is_ok());
+        let error = Region::<u8, OneBased>::try_new(6, 4).unwrap_err();
+        assert_eq!(
+            error,
+            SvartError::InvalidRegionError {
+                start: "6".to_string(),
+                end: "4".to_string()
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Invalid region error: start 6 is after end 4"
+        );
+    }
+
+    #[rstest]
+    #[case(10, 20, 11)]
+    #[case(11, 10, 0)]
+    fn test_span_one_based(#[case] start: u8, #[case] end: u8, #[case] expected: u8) {
+        let region: Region<u8, OneBased> = Region::new(start, end).unwrap();
+        assert_eq!(region.span(), expected);
+        assert_eq!(region.is_empty(), expected == 0);
+    }
+
+    #[rstest]
+    fn test_into_coordinate_system() {
+        let zero_based: Region<u32, ZeroBased> = Region::new(10, 20).unwrap();
+        let one_based = zero_based.clone().into_one_based();
+        assert_eq!(one_based.coordinates(), (&11, &20));
+        assert_eq!(one_based.coordinate_system(), CoordinateSystem::one_based());
+        assert_eq!(one_based.span(), zero_based.span());
+        assert_eq!(one_based.into_zero_based(), zero_based);
+    }
+
+    #[rstest]
+    fn test_overlaps_after_conversion() {
+        // BED `chr1 99 100` and VCF `chr1 100` describe the same base.
+        let bed: Region<u32, ZeroBased> = Region::new(99, 100).unwrap();
+        let vcf: Region<u32, OneBased> = Region::new(100, 100).unwrap();
+        let bed = bed.into_one_based();
+        assert!(bed.overlaps(&vcf));
+        assert!(bed.contains(&vcf));
+        assert!(vcf.contains(&bed));
+
+        let next: Region<u32, OneBased> = Region::new(101, 101).unwrap();
+        assert!(!bed.overlaps(&next));
+    }
+
+    #[rstest]
+    fn test_signed_coordinates() {
+        // c.-45_-1 relative to the CDS start.
+        let region: Region<i32, OneBased> = Region::new(-45, -1).unwrap();
+        assert_eq!(region.span(), 45);
+        assert_eq!(region.into_zero_based().coordinates(), (&-46, &-1));
+    }
+
+    #[rstest]
+    fn test_one_based_start_below_lower_bound() {
+        assert!(Region::<u32, OneBased>::new(0, 0).is_none());
+        assert!(Region::<u32, OneBased>::new(0, 10).is_none());
+        assert!(Region::<i32, OneBased>::new(0, 10).is_some());
+
+        let error = Region::<u32, OneBased>::try_new(0, 10).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+        assert_eq!(
+            error.to_string(),
+            "Out of bounds error: Region 0-10 is before the first position of its coordinate system"
+        );
+    }
+
+    #[rstest]
+    #[case("100-200", Ok(Region::new(100, 200).unwrap()))]
+    #[case("1,000-2,000", Ok(Region::new(1_000, 2_000).unwrap()))]
+    #[case(" 5-5 ", Ok(Region::new(5, 5).unwrap()))]
+    #[case("200-100", Err(SvartError::InvalidRegionError { start: "200".to_string(), end: "100".to_string() }))]
+    #[case("0-10", Err(SvartError::CoordinateOutOfBoundsError { start: "0".to_string(), end: "10".to_string() }))]
+    #[case("100", Err(SvartError::parse_error("Expected 'start-end' but got '100'".to_string(), Some("100"), Some(1))))]
+    #[case("1x0-200", Err(SvartError::parse_error("Invalid coordinate '1x0'".to_string(), Some("1x0"), Some(1))))]
+    #[case(" 100-2x0", Err(SvartError::parse_error("Invalid coordinate '2x0'".to_string(), Some("2x0"), Some(6))))]
+    fn test_from_str(
+        #[case] input: &str,
+        #[case] expected: Result<Region<u32, OneBased>, SvartError>,
+    ) {
+        assert_eq!(input.parse::<Region<u32, OneBased>>(), expected);
+    }
+
+    #[rstest]
+    fn test_display() {
+        let region: Region<i32, OneBased> = Region::new(-45, -1).unwrap();
+        assert_eq!(region.to_string(), "-45--1");
+        assert_eq!(region.to_string().parse(), Ok(region));
     }
 }